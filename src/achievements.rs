@@ -0,0 +1,97 @@
+/*
+DATA TYPES USED:
+
+1. Achievement      -> Enum: Ghost, BruteForcer, Mastermind, FullSweep
+2. ACHIEVEMENTS     -> [Achievement; 4], every achievement in display order
+3. s                -> &str, saved key ("full-sweep")
+4. Err              -> (), unit type for error
+5. list             -> &[Achievement], achievements an operator has unlocked
+6. text             -> String, comma-separated keys as saved in a profile
+7. lines            -> Vec<String>, text shown to the operator
+8. tr!              -> macro (from i18n), message text in the chosen language
+*/
+
+use std::str::FromStr; // Parse saved achievement keys
+
+use crate::i18n::tr; // Translated text
+
+// Enum for feats an operator can unlock once
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Achievement {
+    Ghost,       // Win without blowing up a node
+    BruteForcer, // Win after brute-forcing the code lock
+    Mastermind,  // Crack the vault code in two guesses or fewer
+    FullSweep,   // Hold every node of the firewall
+}
+
+// Every achievement, in display order
+pub const ACHIEVEMENTS: [Achievement; 4] = [
+    Achievement::Ghost,
+    Achievement::BruteForcer,
+    Achievement::Mastermind,
+    Achievement::FullSweep,
+];
+
+// Guesses allowed for Mastermind
+pub const MASTERMIND_GUESSES: u32 = 2;
+
+// Convert saved keys into Achievement
+impl FromStr for Achievement {
+    type Err = (); // Unit type () used as error
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ACHIEVEMENTS.into_iter().find(|a| a.key() == s.trim()).ok_or(())
+    }
+}
+
+impl Achievement {
+    // Key used in profile files
+    pub fn key(&self) -> &'static str {
+        match self {
+            Achievement::Ghost => "ghost",
+            Achievement::BruteForcer => "brute-forcer",
+            Achievement::Mastermind => "mastermind",
+            Achievement::FullSweep => "full-sweep",
+        }
+    }
+
+    // Display name
+    pub fn name(&self) -> String {
+        match self {
+            Achievement::Ghost => tr!("ach.ghost"),
+            Achievement::BruteForcer => tr!("ach.brute_forcer"),
+            Achievement::Mastermind => tr!("ach.mastermind"),
+            Achievement::FullSweep => tr!("ach.full_sweep"),
+        }
+    }
+
+    // How to unlock it
+    pub fn describe(&self) -> String {
+        match self {
+            Achievement::Ghost => tr!("ach.ghost_how"),
+            Achievement::BruteForcer => tr!("ach.brute_forcer_how"),
+            Achievement::Mastermind => tr!("ach.mastermind_how"),
+            Achievement::FullSweep => tr!("ach.full_sweep_how"),
+        }
+    }
+}
+
+// Parse "ghost,mastermind" from a profile (unknown keys are skipped)
+pub fn parse_list(text: &str) -> Vec<Achievement> {
+    text.split(',').filter_map(|s| s.parse().ok()).collect()
+}
+
+// Write a list as "ghost,mastermind"
+pub fn format_list(list: &[Achievement]) -> String {
+    list.iter().map(|a| a.key()).collect::<Vec<_>>().join(",")
+}
+
+// Every achievement, marked when unlocked
+pub fn checklist(list: &[Achievement]) -> Vec<String> {
+    let mut lines = vec![tr!("ach.count", list.len(), ACHIEVEMENTS.len())];
+    for a in ACHIEVEMENTS {
+        let mark = if list.contains(&a) { "[x]" } else { "[ ]" };
+        lines.push(format!("  {} {} — {}", mark, a.name(), a.describe()));
+    }
+    lines
+}
@@ -1,38 +1,108 @@
 /*
 DATA TYPES USED:
 
-1. Action           -> Enum: Scan, Hack, BruteForce, Retreat, Status, Help
+1. Action           -> Enum: Scan, Hack, Route, FollowRoute, Probe, BruteForce, LieLow, Undo, Retreat, Status, Analyze, Help
 2. s                 -> &str, input string
 3. Err               -> (), unit type for error
 4. Result<Self, Self::Err> -> Result type returned by from_str
+5. words             -> Vec<&str>, command word followed by arguments
+6. ScanTarget        -> Enum (from security::hints): Any, Code, Grid
+7. r, c              -> usize, node coordinates given inline ("hack 2 3")
+8. input             -> String, attempt at the current vault lock ("guess 1234", "guess 0 1 0 2 1 2")
+9. steps             -> usize, turns to rewind in practice mode ("undo 2")
+10. go               -> bool, queue the route instead of only showing it ("route 2 3 go")
+11. Option<(usize, usize)> -> node to probe, or None for the whole grid ("probe 2 3", "probe")
 */
 
 use std::str::FromStr; // Import FromStr trait to convert strings into enum
 
+use crate::security::hints::ScanTarget; // What a scan is aimed at
+
 // Enum representing possible player actions
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum Action { // enum
-    Scan,        // Scan the security system
-    Hack,        // Attempt to hack a node or code
+    Scan(ScanTarget), // Scan the security system (optionally targeted)
+    Hack,        // Attempt to hack a node or code (asks for the target)
+    HackNode(usize, usize), // Hack the firewall node at (row, col)
+    Route(usize, usize, bool), // Plan the cheapest route to (row, col); true queues it
+    FollowRoute, // Hack the next node on the queued route
+    Probe(Option<(usize, usize)>), // Hack odds of one node, or of the whole grid
+    Guess(String),          // Attempt the current vault lock (code, pattern, timing)
     BruteForce,  // Attempt brute force attack
+    LieLow,      // Spend the turn quietly so the IDS calms down
+    Undo(usize), // Rewind this many turns (practice mode only)
     Retreat,     // Exit or retreat
     Status,      // Check player/system status
+    Analyze,     // Count codes consistent with notes so far
     Help,        // Display help/instructions
 }
 
+impl Action {
+    // Actions that only show information and never use up a turn
+    pub fn is_free(&self) -> bool {
+        matches!(self, Action::Status | Action::Analyze | Action::Help | Action::Route(_, _, false) | Action::Probe(_))
+    }
+
+    // Name used in lifetime stats
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Action::Scan(_) => "scan",
+            Action::Hack | Action::HackNode(..) => "hack",
+            Action::Route(..) | Action::FollowRoute => "route",
+            Action::Probe(_) => "probe",
+            Action::Guess(_) => "guess",
+            Action::BruteForce => "brute",
+            Action::LieLow => "wait",
+            Action::Undo(_) => "undo",
+            Action::Retreat => "retreat",
+            Action::Status => "status",
+            Action::Analyze => "analyze",
+            Action::Help => "help",
+        }
+    }
+}
+
 // Implement conversion from string to Action enum
 impl FromStr for Action {
     type Err = (); // Unit type () used as error
 
     fn from_str(s: &str) -> Result<Self, Self::Err> { // returns Result<Action, ()>
-        match s.trim().to_lowercase().as_str() { // convert input string to lowercase and trim whitespace
-            "scan" | "s" => Ok(Action::Scan),               // map "scan" or "s" to Action::Scan
-            "hack" | "h" => Ok(Action::Hack),               // map "hack" or "h" to Action::Hack
-            "brute" | "b" => Ok(Action::BruteForce),       // map "brute" or "b" to Action::BruteForce
-            "retreat" | "r" => Ok(Action::Retreat),        // map "retreat" or "r" to Action::Retreat
-            "status" => Ok(Action::Status),                // map "status" to Action::Status
-            "help" => Ok(Action::Help),                    // map "help" to Action::Help
-            _ => Err(()),                                  // if none match, return error ()
+        let lower = s.trim().to_lowercase();                  // normalise case and whitespace
+        let words: Vec<&str> = lower.split_whitespace().collect(); // command word + arguments
+
+        match words.as_slice() {
+            ["scan" | "s"] => Ok(Action::Scan(ScanTarget::Any)),                 // broad scan
+            ["scan" | "s", "code" | "c"] => Ok(Action::Scan(ScanTarget::Code)), // targeted code scan
+            ["scan" | "s", "grid" | "g"] => Ok(Action::Scan(ScanTarget::Grid)), // targeted grid scan
+            ["hack" | "h"] => Ok(Action::Hack),             // map "hack" or "h" to Action::Hack
+            ["hack" | "h", r, c] => {                       // "hack 2 3" targets a node directly
+                let r: usize = r.parse().map_err(|_| ())?;
+                let c: usize = c.parse().map_err(|_| ())?;
+                Ok(Action::HackNode(r, c))
+            }
+            ["route", r, c, rest @ ..] if matches!(rest, [] | ["go"]) => {   // "route 2 3" shows, "route 2 3 go" queues
+                let r: usize = r.parse().map_err(|_| ())?;
+                let c: usize = c.parse().map_err(|_| ())?;
+                Ok(Action::Route(r, c, !rest.is_empty()))
+            }
+            ["route"] => Ok(Action::FollowRoute),           // next hack on the queued route
+            ["probe"] => Ok(Action::Probe(None)),           // odds overlay for the grid
+            ["probe", r, c] => {                            // "probe 2 3" details one node
+                let r: usize = r.parse().map_err(|_| ())?;
+                let c: usize = c.parse().map_err(|_| ())?;
+                Ok(Action::Probe(Some((r, c))))
+            }
+            ["guess" | "g", rest @ ..] if !rest.is_empty() => Ok(Action::Guess(rest.join(" "))), // "guess 1234"
+            ["sync"] => Ok(Action::Guess("now".to_string())), // timing lock shorthand
+            ["brute" | "b"] => Ok(Action::BruteForce),      // map "brute" or "b" to Action::BruteForce
+            ["wait" | "w"] | ["lie", "low"] => Ok(Action::LieLow), // go quiet for a turn
+            ["undo" | "u"] => Ok(Action::Undo(1)),           // rewind one turn (practice mode)
+            ["undo" | "u", n] => Ok(Action::Undo(n.parse().map_err(|_| ())?)), // "undo 3"
+            ["retreat" | "r"] => Ok(Action::Retreat),       // map "retreat" or "r" to Action::Retreat
+            ["status"] => Ok(Action::Status),               // map "status" to Action::Status
+            ["analyze" | "a"] => Ok(Action::Analyze),       // map "analyze" or "a" to Action::Analyze
+            ["help"] => Ok(Action::Help),                   // map "help" to Action::Help
+            _ => Err(()),                                   // if none match, return error ()
         }
     }
 }
@@ -0,0 +1,248 @@
+/*
+DATA TYPES USED:
+
+1. Launch           -> Enum: Play(RuleOptions), Tutorial(Style), Daily(Style), Server(ServerConfig), Connect(String), Profile(String, Option<Skill>), Achievements(String), Stats(String, Option<Difficulty>), Mods
+2. args             -> &[String], command-line arguments after the program name
+3. config           -> ServerConfig struct, settings for a hosted heist
+4. flag, value      -> &str, option name and its argument
+5. Result<Launch, String> -> parsed launch mode or an error message
+6. rules            -> RuleOptions struct, rule overrides shared by local and hosted games
+7. handle, train    -> String / Option<Skill>, profile to show and a skill to spend a point on
+8. lang             -> Option<String>, language code given with --lang
+9. style            -> Style struct, theme and screen-reader option for tutorial and daily runs
+10. tr!             -> macro (from i18n), message text in the chosen language
+*/
+
+use crate::engine::{PlayMode, RuleOptions, MAX_OPERATORS}; // Play mode, rule overrides and seat limit
+use crate::net::server::ServerConfig;           // Server settings
+use crate::profile::Skill;                      // Skill tree branches
+use crate::net::DEFAULT_ADDR;                   // Default host:port
+use crate::security::Difficulty;                // Difficulty enum
+use crate::security::defense::DefenderKind;     // Sysadmin controller
+use crate::security::vault::parse_kinds;        // Vault lock list
+use crate::theme::{Style, Theme};               // Colors and screen-reader output
+use crate::i18n::tr;                            // Translated text
+
+// Enum describing what the program was asked to do
+pub enum Launch {
+    Play(RuleOptions),    // Local terminal game (default)
+    Tutorial(Style),      // Guided first mission on a fixed board
+    Daily(Style),         // Today's date-seeded challenge
+    Server(ServerConfig), // Host a heist over TCP
+    Connect(String),      // Join a hosted heist at this address
+    Profile(String, Option<Skill>), // Show an operator profile, optionally training a skill
+    Achievements(String), // Show an operator's achievements
+    Stats(String, Option<Difficulty>), // Show an operator's lifetime stats, optionally for one difficulty
+    Mods,                 // Show the loaded content packs
+}
+
+// Usage text printed on bad arguments
+pub fn usage() -> String {
+    tr!("cli.usage")
+}
+
+// Remove `--lang CODE` from anywhere in the arguments and return the code
+pub fn take_lang(args: &mut Vec<String>) -> Result<Option<String>, String> {
+    let Some(i) = args.iter().position(|a| a == "--lang") else {
+        return Ok(None);
+    };
+    if i + 1 >= args.len() {
+        return Err(tr!("cli.lang_code"));
+    }
+    let code = args.remove(i + 1);
+    args.remove(i);
+    Ok(Some(code))
+}
+
+// Parse command-line arguments into a launch mode
+pub fn parse_args(args: &[String]) -> Result<Launch, String> {
+    match args.first().map(|s| s.as_str()) {
+        None => Ok(Launch::Play(RuleOptions::default())),
+        Some(flag) if flag.starts_with("--") => parse_play(args).map(Launch::Play),
+        Some("tutorial") => parse_style(&args[1..]).map(Launch::Tutorial),
+        Some("daily") => parse_style(&args[1..]).map(Launch::Daily),
+        Some("mods") => Ok(Launch::Mods),
+        Some("server") => parse_server(&args[1..]).map(Launch::Server),
+        Some("connect") => Ok(Launch::Connect(
+            args.get(1).cloned().unwrap_or_else(|| DEFAULT_ADDR.to_string()),
+        )),
+        Some("profile") => parse_profile(&args[1..]),
+        Some("stats") => parse_stats(&args[1..]),
+        Some("achievements") => args
+            .get(1)
+            .map(|handle| Launch::Achievements(handle.clone()))
+            .ok_or_else(|| tr!("cli.achievements_handle")),
+        Some(other) => Err(tr!("cli.unknown_command", other)),
+    }
+}
+
+// Parse `profile HANDLE [train SKILL]`
+fn parse_profile(args: &[String]) -> Result<Launch, String> {
+    let handle = args.first().ok_or_else(|| tr!("cli.profile_handle"))?;
+    match &args[1..] {
+        [] => Ok(Launch::Profile(handle.clone(), None)),
+        [verb, skill] if verb == "train" => skill
+            .parse::<Skill>()
+            .map(|s| Launch::Profile(handle.clone(), Some(s)))
+            .map_err(|_| tr!("cli.skill")),
+        _ => Err(tr!("cli.profile_usage")),
+    }
+}
+
+// Parse `stats HANDLE [DIFFICULTY]`
+fn parse_stats(args: &[String]) -> Result<Launch, String> {
+    match args {
+        [handle] => Ok(Launch::Stats(handle.clone(), None)),
+        [handle, difficulty] => difficulty
+            .parse::<Difficulty>()
+            .map(|d| Launch::Stats(handle.clone(), Some(d)))
+            .map_err(|_| tr!("cli.stats_difficulty")),
+        _ => Err(tr!("cli.stats_usage")),
+    }
+}
+
+// Parse options for a local game (rule options plus --practice, --theme and --screen-reader)
+fn parse_play(args: &[String]) -> Result<RuleOptions, String> {
+    let mut rules = RuleOptions::default();
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        // Local-only flags that take no value
+        if flag == "--practice" {
+            rules.practice = true;
+            continue;
+        }
+        if flag == "--screen-reader" {
+            rules.screen_reader = true;
+            continue;
+        }
+        let value = iter
+            .next()
+            .ok_or_else(|| tr!("cli.missing_value", flag))?;
+        if flag == "--theme" {
+            rules.theme = Some(parse_theme(value)?);
+            continue;
+        }
+        if !parse_rule(&mut rules, flag, value)? {
+            return Err(tr!("cli.unknown_option", flag));
+        }
+    }
+    Ok(rules)
+}
+
+// Parse a --theme value
+fn parse_theme(value: &str) -> Result<Theme, String> {
+    value
+        .parse()
+        .map_err(|_| tr!("cli.theme"))
+}
+
+// Parse the display options of `tutorial` and `daily` (--theme and --screen-reader)
+fn parse_style(args: &[String]) -> Result<Style, String> {
+    let mut theme = None;
+    let mut screen_reader = false;
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        match flag.as_str() {
+            "--screen-reader" => screen_reader = true,
+            "--theme" => {
+                let value = iter.next().ok_or_else(|| tr!("cli.missing_value", "--theme"))?;
+                theme = Some(parse_theme(value)?);
+            }
+            _ => return Err(tr!("cli.unknown_option", flag)),
+        }
+    }
+    Ok(Style::for_terminal(theme, screen_reader))
+}
+
+// Apply one rule option; Ok(false) if the flag is not a rule option
+fn parse_rule(rules: &mut RuleOptions, flag: &str, value: &str) -> Result<bool, String> {
+    let number = || {
+        value
+            .parse::<u32>()
+            .map_err(|_| tr!("cli.number", flag))
+    };
+    match flag {
+        "--vault" => rules.vault = Some(parse_kinds(value)?),
+        "--script" => rules.script = Some(value.to_string()),
+        "--seed" => {
+            rules.seed = Some(
+                value
+                    .parse::<u64>()
+                    .map_err(|_| tr!("cli.number", "--seed"))?,
+            )
+        }
+        "--rotate-every" => rules.rotate_every = Some(number()?),
+        "--rotate-alert" => rules.rotate_alert = Some(number()?),
+        "--rotate-digits" => {
+            rules.rotate_digits = Some(
+                value
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|n| (1..=4).contains(n))
+                    .ok_or_else(|| tr!("cli.rotate_digits"))?,
+            )
+        }
+        "--reach" => {
+            rules.edge_reach = match value {
+                "any" => false,
+                "edge" => true,
+                _ => return Err(tr!("cli.reach")),
+            }
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+// Parse `server` options
+fn parse_server(args: &[String]) -> Result<ServerConfig, String> {
+    let mut config = ServerConfig {
+        addr: DEFAULT_ADDR.to_string(),
+        players: 2,
+        difficulty: Difficulty::Normal,
+        mode: PlayMode::Competitive,
+        defender: None,
+        rules: RuleOptions::default(),
+    };
+
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        let value = iter
+            .next()
+            .ok_or_else(|| tr!("cli.missing_value", flag))?;
+        match flag.as_str() {
+            "--addr" => config.addr = value.clone(),
+            "--players" => {
+                config.players = value
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|n| (1..=MAX_OPERATORS).contains(n))
+                    .ok_or_else(|| tr!("cli.players", MAX_OPERATORS))?
+            }
+            "--mode" => {
+                config.mode = value
+                    .parse()
+                    .map_err(|_| tr!("cli.mode"))?
+            }
+            "--difficulty" => {
+                config.difficulty = value
+                    .parse()
+                    .map_err(|_| tr!("cli.difficulty"))?
+            }
+            "--defender" => {
+                config.defender = match value.as_str() {
+                    "none" => None,
+                    "ai" => Some(DefenderKind::Ai),
+                    "human" => Some(DefenderKind::Human),
+                    _ => return Err(tr!("cli.defender")),
+                }
+            }
+            _ => {
+                if !parse_rule(&mut config.rules, flag, value)? {
+                    return Err(tr!("cli.unknown_option", flag));
+                }
+            }
+        }
+    }
+    Ok(config)
+}
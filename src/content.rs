@@ -0,0 +1,585 @@
+/*
+DATA TYPES USED:
+
+1. Content           -> Struct: difficulty profiles plus everything added by content packs
+2. DifficultyProfile -> Struct: lock timer and firewall generation numbers for one difficulty
+3. NodeType          -> Struct: a custom firewall node (share of the grid, difficulty range, honeypot odds, siphon)
+4. Item              -> Struct: a custom inventory item that may restore energy each turn
+5. EventDef          -> Struct: a custom security event and what it does to the operator
+6. HintDef           -> Struct: extra wording for a grid scan result
+7. HintOn            -> Enum: Honeypot, Exploit, Clear, the grid scan result a hint describes
+8. Field             -> Enum: what a schema field accepts (Text, Number(min, max), Flag, Choice)
+9. SCHEMA            -> [Section; 6], every section, its fields and which fields are required
+10. Entry            -> Struct: one validated "section.id.field=value" line of a pack
+11. CONTENT          -> OnceLock<Content>, the content loaded at startup
+12. dirs             -> &[PathBuf], folders searched for packs (*.txt, in name order)
+13. errors           -> Vec<String>, schema problems ("mods/x.txt line 4: ...")
+14. report           -> Vec<String>, what was loaded or skipped, shown at startup
+15. tr!              -> macro (from i18n), message text in the chosen language
+*/
+
+use std::fs;             // Read pack files
+use std::path::{Path, PathBuf}; // Pack locations
+use std::str::FromStr;   // Parse hint targets
+use std::sync::OnceLock; // Content loaded once at startup
+
+use crate::energy; // Default siphon
+use crate::i18n::tr; // Translated text
+use crate::security::Difficulty; // Difficulty enum
+use crate::storage; // Data folder
+
+// Folder next to the game that holds content packs
+pub const MODS_DIR: &str = "mods";
+
+// Weight of each built-in security event (a pack event's default weight too)
+pub const BUILT_IN_WEIGHT: u32 = 10;
+
+// Struct for the numbers one difficulty is built from
+#[derive(Debug, Clone, Copy)]
+pub struct DifficultyProfile {
+    pub lock_timer: i32, // Turns before the vault locks down
+    pub vulnerable: u32, // Percent of plain nodes that start vulnerable
+    pub node_min: i32,   // Lowest node difficulty
+    pub node_max: i32,   // Highest node difficulty
+    pub honeypots: u32,  // Percent of plain nodes that are honeypots
+}
+
+// Struct for a custom firewall node
+#[derive(Debug, Clone)]
+pub struct NodeType {
+    pub id: String,       // Key used in the pack
+    pub name: String,     // Shown when the node is hacked
+    pub share: u32,       // Percent of the grid made of this node
+    pub vulnerable: bool, // Starts vulnerable instead of secure
+    pub min: i32,         // Lowest difficulty
+    pub max: i32,         // Highest difficulty
+    pub honeypot: u32,    // Percent chance the node is a honeypot
+    pub siphon: i32,      // Energy drawn on a successful hack
+}
+
+// Struct for a custom inventory item
+#[derive(Debug, Clone)]
+pub struct Item {
+    pub id: String,   // Key used in the pack
+    pub name: String, // Name in the inventory
+    pub start: bool,  // Every operator starts with one
+    pub chance: u32,  // Percent chance per turn to fire
+    pub energy: i32,  // Energy restored when it fires
+    pub text: String, // Shown when it fires
+}
+
+// Struct for a custom security event
+#[derive(Debug, Clone)]
+pub struct EventDef {
+    pub id: String,   // Key used in the pack
+    pub text: String, // Shown when it fires; {0} is the operator
+    pub weight: u32,  // How often it is picked next to the built-in events
+    pub timer: i32,   // Lock timer change
+    pub energy: i32,  // Operator energy change
+    pub alert: i32,   // IDS alert change
+}
+
+// Enum for the grid scan result a hint describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HintOn {
+    Honeypot, // A honeypot was flagged at {0},{1}
+    Exploit,  // Node {0},{1} turned vulnerable, difficulty {2}
+    Clear,    // Nothing left to find
+}
+
+// Struct for extra grid scan wording
+#[derive(Debug, Clone)]
+pub struct HintDef {
+    pub id: String,   // Key used in the pack
+    pub on: HintOn,   // Scan result it describes
+    pub text: String, // Hint text with {0}, {1}, {2} placeholders
+}
+
+// Struct holding all game content
+#[derive(Debug, Clone)]
+pub struct Content {
+    pub packs: Vec<String>,                 // Names of the packs loaded
+    pub profiles: [DifficultyProfile; 3],   // Easy, normal, hard
+    pub nodes: Vec<NodeType>,               // Custom node types
+    pub items: Vec<Item>,                   // Custom items
+    pub events: Vec<EventDef>,              // Custom security events
+    pub hints: Vec<HintDef>,                // Extra grid scan wording
+}
+
+// Enum for what a field accepts
+#[derive(Debug, Clone, Copy)]
+enum Field {
+    Text,                     // Any non-empty text
+    Number(i64, i64),         // Whole number in this range
+    Flag,                     // yes / no
+    Choice(&'static [&'static str]), // One of these words
+}
+
+// Struct for one section of the schema
+struct Section {
+    name: &'static str,                           // "node", "item", ...
+    ids: Option<&'static [&'static str]>,         // Allowed ids (None = any id)
+    fields: &'static [(&'static str, Field, bool)], // Field, what it accepts, required
+}
+
+// Every section a pack may use
+const SCHEMA: [Section; 6] = [
+    Section {
+        name: "pack",
+        ids: Some(&[]),
+        fields: &[("name", Field::Text, true), ("author", Field::Text, false)],
+    },
+    Section {
+        name: "node",
+        ids: None,
+        fields: &[
+            ("name", Field::Text, true),
+            ("share", Field::Number(1, 100), true),
+            ("state", Field::Choice(&["secure", "vulnerable"]), false),
+            ("min", Field::Number(1, 99), true),
+            ("max", Field::Number(1, 99), true),
+            ("honeypot", Field::Number(0, 100), false),
+            ("siphon", Field::Number(0, 50), false),
+        ],
+    },
+    Section {
+        name: "item",
+        ids: None,
+        fields: &[
+            ("name", Field::Text, true),
+            ("start", Field::Flag, false),
+            ("chance", Field::Number(0, 100), false),
+            ("energy", Field::Number(0, 50), false),
+            ("text", Field::Text, false),
+        ],
+    },
+    Section {
+        name: "event",
+        ids: None,
+        fields: &[
+            ("text", Field::Text, true),
+            ("weight", Field::Number(1, 100), false),
+            ("timer", Field::Number(-5, 5), false),
+            ("energy", Field::Number(-50, 50), false),
+            ("alert", Field::Number(-50, 50), false),
+        ],
+    },
+    Section {
+        name: "hint",
+        ids: None,
+        fields: &[
+            ("on", Field::Choice(&["honeypot", "exploit", "clear"]), true),
+            ("text", Field::Text, true),
+        ],
+    },
+    Section {
+        name: "difficulty",
+        ids: Some(&["easy", "normal", "hard"]),
+        fields: &[
+            ("lock_timer", Field::Number(5, 99), false),
+            ("vulnerable", Field::Number(0, 100), false),
+            ("node_min", Field::Number(1, 99), false),
+            ("node_max", Field::Number(1, 99), false),
+            ("honeypots", Field::Number(0, 100), false),
+        ],
+    },
+];
+
+// Struct for one validated line of a pack
+struct Entry {
+    line: usize,    // Line number in the file
+    section: usize, // Index into SCHEMA
+    id: String,     // "" for the pack section
+    field: String,  // Field name
+    value: String,  // Checked value
+}
+
+static CONTENT: OnceLock<Content> = OnceLock::new();
+
+// Convert "honeypot" / "exploit" / "clear" into HintOn
+impl FromStr for HintOn {
+    type Err = (); // Unit type () used as error
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "honeypot" => Ok(HintOn::Honeypot),
+            "exploit" => Ok(HintOn::Exploit),
+            "clear" => Ok(HintOn::Clear),
+            _ => Err(()),
+        }
+    }
+}
+
+impl DifficultyProfile {
+    // The numbers the game ships with
+    pub fn built_in(difficulty: Difficulty) -> Self {
+        match difficulty {
+            Difficulty::Easy => DifficultyProfile { lock_timer: 25, vulnerable: 45, node_min: 5, node_max: 29, honeypots: 8 },
+            Difficulty::Normal => DifficultyProfile { lock_timer: 20, vulnerable: 30, node_min: 20, node_max: 59, honeypots: 12 },
+            Difficulty::Hard => DifficultyProfile { lock_timer: 16, vulnerable: 15, node_min: 40, node_max: 94, honeypots: 18 },
+        }
+    }
+}
+
+impl Default for Content {
+    fn default() -> Self {
+        Content {
+            packs: Vec::new(),
+            profiles: [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard].map(DifficultyProfile::built_in),
+            nodes: Vec::new(),
+            items: Vec::new(),
+            events: Vec::new(),
+            hints: Vec::new(),
+        }
+    }
+}
+
+// Index of a difficulty in Content::profiles
+fn slot(difficulty: Difficulty) -> usize {
+    match difficulty {
+        Difficulty::Easy => 0,
+        Difficulty::Normal => 1,
+        Difficulty::Hard => 2,
+    }
+}
+
+impl Content {
+    // Numbers for one difficulty
+    pub fn profile(&self, difficulty: Difficulty) -> &DifficultyProfile {
+        &self.profiles[slot(difficulty)]
+    }
+
+    // Summary shown by `cyberheist mods`
+    pub fn summary_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if self.packs.is_empty() {
+            lines.push(tr!("mods.none"));
+        } else {
+            lines.push(tr!("mods.packs", self.packs.join(", ")));
+        }
+        for (d, p) in [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard].iter().zip(&self.profiles) {
+            let row = tr!("mods.profile", p.lock_timer, p.vulnerable, p.node_min, p.node_max, p.honeypots);
+            lines.push(format!("  {:<8} {}", d.label(), row));
+        }
+        for n in &self.nodes {
+            lines.push(format!("  {}", tr!("mods.node", n.id, n.name, n.share, n.min, n.max, n.honeypot, n.siphon)));
+        }
+        for i in &self.items {
+            let key = if i.start { "mods.item_start" } else { "mods.item" };
+            lines.push(format!("  {}", tr!(key, i.id, i.name, i.chance, i.energy)));
+        }
+        for e in &self.events {
+            let signed = |n: i32| format!("{:+}", n);
+            lines.push(format!("  {}", tr!("mods.event", e.id, e.weight, signed(e.timer), signed(e.energy), signed(e.alert))));
+        }
+        for h in &self.hints {
+            let on = match h.on {
+                HintOn::Honeypot => tr!("mods.on_honeypot"),
+                HintOn::Exploit => tr!("mods.on_exploit"),
+                HintOn::Clear => tr!("mods.on_clear"),
+            };
+            lines.push(format!("  {}", tr!("mods.hint", h.id, on)));
+        }
+        lines
+    }
+}
+
+// Content in use (built-in only until `load` runs)
+pub fn get() -> &'static Content {
+    CONTENT.get_or_init(Content::default)
+}
+
+// Folders searched for packs: ./mods, then <data dir>/mods
+pub fn mod_dirs() -> Vec<PathBuf> {
+    vec![PathBuf::from(MODS_DIR), storage::data_dir().join(MODS_DIR)]
+}
+
+// Load every pack in the folders; packs that break the schema are skipped whole
+pub fn load(dirs: &[PathBuf]) -> Vec<String> {
+    let mut content = Content::default();
+    let mut report = Vec::new();
+    for dir in dirs {
+        let Ok(read) = fs::read_dir(dir) else {
+            continue; // No mods folder
+        };
+        let mut files: Vec<PathBuf> = read
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.extension().is_some_and(|x| x == "txt"))
+            .collect();
+        files.sort();
+        for path in files {
+            match load_pack(&path, &content) {
+                Ok(pack) => {
+                    report.push(tr!("mods.loaded", pack.packs[0], path.display()));
+                    merge(&mut content, pack);
+                }
+                Err(errors) => {
+                    report.extend(errors);
+                    report.push(tr!("mods.skipped", path.display()));
+                }
+            }
+        }
+    }
+    let _ = CONTENT.set(content);
+    report
+}
+
+// Check a field's value against the schema
+fn check(field: Field, value: &str) -> Result<String, String> {
+    match field {
+        Field::Text if value.is_empty() => Err(tr!("mods.needs_text")),
+        Field::Text => Ok(value.to_string()),
+        Field::Number(min, max) => match value.parse::<i64>() {
+            Ok(n) if (min..=max).contains(&n) => Ok(n.to_string()),
+            _ => Err(tr!("mods.number", min, max)),
+        },
+        Field::Flag => match value.to_lowercase().as_str() {
+            "yes" | "true" | "1" => Ok("yes".to_string()),
+            "no" | "false" | "0" => Ok("no".to_string()),
+            _ => Err(tr!("mods.flag")),
+        },
+        Field::Choice(words) => match words.iter().find(|w| **w == value.to_lowercase()) {
+            Some(w) => Ok(w.to_string()),
+            None => Err(tr!("mods.choice", words.join(", "))),
+        },
+    }
+}
+
+// Check one "section.id.field" key and its value
+fn parse_entry(line: usize, key: &str, value: &str) -> Result<Entry, String> {
+    let parts: Vec<&str> = key.split('.').collect();
+    let Some(section) = SCHEMA.iter().position(|s| s.name == parts[0]) else {
+        let names: Vec<&str> = SCHEMA.iter().map(|s| s.name).collect();
+        return Err(tr!("mods.unknown_section", parts[0], names.join(", ")));
+    };
+    let schema = &SCHEMA[section];
+    let (id, field) = match (schema.ids, parts.as_slice()) {
+        (Some([]), [_, field]) => ("", *field),
+        (Some([]), _) => return Err(tr!("mods.expected_field", schema.name)),
+        (_, [_, id, field]) if !id.is_empty() => (*id, *field),
+        _ => return Err(tr!("mods.expected_id", schema.name)),
+    };
+    if let Some(ids) = schema.ids
+        && !ids.is_empty()
+        && !ids.contains(&id)
+    {
+        return Err(tr!("mods.bad_id", schema.name, ids.join(", ")));
+    }
+    let Some(&(_, kind, _)) = schema.fields.iter().find(|(name, _, _)| *name == field) else {
+        let names: Vec<&str> = schema.fields.iter().map(|(name, _, _)| *name).collect();
+        return Err(tr!("mods.unknown_field", field, schema.name, names.join(", ")));
+    };
+    let value = check(kind, value).map_err(|e| format!("{} {}", key, e))?;
+    Ok(Entry {
+        line,
+        section,
+        id: id.to_string(),
+        field: field.to_string(),
+        value,
+    })
+}
+
+// Read and validate one pack file into content of its own
+fn load_pack(path: &Path, loaded: &Content) -> Result<Content, Vec<String>> {
+    let text = fs::read_to_string(path).map_err(|e| vec![tr!("mods.in_file", path.display(), e)])?;
+    let mut errors = Vec::new();
+    let mut entries: Vec<Entry> = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let result = match line.split_once('=') {
+            Some((k, v)) => parse_entry(i + 1, k.trim(), v.trim()),
+            None => Err(tr!("mods.key_value")),
+        };
+        match result {
+            Ok(e) if entries.iter().any(|o| o.section == e.section && o.id == e.id && o.field == e.field) => {
+                errors.push(tr!("mods.at_line", path.display(), i + 1, tr!("mods.twice", e.field)));
+            }
+            Ok(e) => entries.push(e),
+            Err(msg) => errors.push(tr!("mods.at_line", path.display(), i + 1, msg)),
+        }
+    }
+
+    // Every id of every section, in file order
+    let mut groups: Vec<(usize, String, usize)> = Vec::new(); // Section, id, first line
+    for e in &entries {
+        if !groups.iter().any(|(s, id, _)| *s == e.section && *id == e.id) {
+            groups.push((e.section, e.id.clone(), e.line));
+        }
+    }
+    if !groups.iter().any(|(s, _, _)| SCHEMA[*s].name == "pack") {
+        errors.push(tr!("mods.in_file", path.display(), tr!("mods.no_name")));
+    }
+
+    let mut pack = Content { packs: Vec::new(), profiles: loaded.profiles, ..Content::default() };
+    for (section, id, line) in groups {
+        let schema = &SCHEMA[section];
+        let value = |field: &str| {
+            entries
+                .iter()
+                .find(|e| e.section == section && e.id == id && e.field == field)
+                .map(|e| e.value.clone())
+        };
+        let missing: Vec<&str> = schema
+            .fields
+            .iter()
+            .filter(|(name, _, required)| *required && value(name).is_none())
+            .map(|(name, _, _)| *name)
+            .collect();
+        if !missing.is_empty() {
+            let what = if id.is_empty() { schema.name.to_string() } else { format!("{}.{}", schema.name, id) };
+            errors.push(tr!("mods.at_line", path.display(), line, tr!("mods.missing", what, missing.join(", "))));
+            continue;
+        }
+        let number = |field: &str, default: i64| value(field).and_then(|v| v.parse::<i64>().ok()).unwrap_or(default);
+        let text = |field: &str| value(field).unwrap_or_default();
+        match schema.name {
+            "pack" => pack.packs.push(text("name")),
+            "node" => {
+                let (min, max) = (number("min", 0) as i32, number("max", 0) as i32);
+                if min > max {
+                    errors.push(tr!("mods.at_line", path.display(), line, tr!("mods.node_range", id)));
+                }
+                pack.nodes.push(NodeType {
+                    name: text("name"),
+                    share: number("share", 0) as u32,
+                    vulnerable: text("state") == "vulnerable",
+                    min,
+                    max,
+                    honeypot: number("honeypot", 0) as u32,
+                    siphon: number("siphon", energy::SIPHON as i64) as i32,
+                    id,
+                });
+            }
+            "item" => pack.items.push(Item {
+                name: text("name"),
+                start: text("start") == "yes",
+                chance: number("chance", 0) as u32,
+                energy: number("energy", 0) as i32,
+                text: value("text").unwrap_or_else(|| tr!("mods.item_fired", text("name"))),
+                id,
+            }),
+            "event" => pack.events.push(EventDef {
+                text: text("text"),
+                weight: number("weight", BUILT_IN_WEIGHT as i64) as u32,
+                timer: number("timer", 0) as i32,
+                energy: number("energy", 0) as i32,
+                alert: number("alert", 0) as i32,
+                id,
+            }),
+            "hint" => pack.hints.push(HintDef {
+                on: text("on").parse().unwrap_or(HintOn::Clear),
+                text: text("text"),
+                id,
+            }),
+            "difficulty" => {
+                let Ok(d) = id.parse::<Difficulty>() else { continue };
+                let p = &mut pack.profiles[slot(d)];
+                p.lock_timer = number("lock_timer", p.lock_timer as i64) as i32;
+                p.vulnerable = number("vulnerable", p.vulnerable as i64) as u32;
+                p.node_min = number("node_min", p.node_min as i64) as i32;
+                p.node_max = number("node_max", p.node_max as i64) as i32;
+                p.honeypots = number("honeypots", p.honeypots as i64) as u32;
+                if p.node_min > p.node_max {
+                    errors.push(tr!("mods.at_line", path.display(), line, tr!("mods.difficulty_range", id)));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Node types share one roll per node, so together they cannot pass 100%
+    let share = |list: &[NodeType], skip: &[NodeType]| -> u32 {
+        list.iter().filter(|n| !skip.iter().any(|s| s.id == n.id)).map(|n| n.share).sum()
+    };
+    let total = share(&loaded.nodes, &pack.nodes) + share(&pack.nodes, &[]);
+    if total > 100 {
+        errors.push(tr!("mods.in_file", path.display(), tr!("mods.shares", total)));
+    }
+
+    if errors.is_empty() { Ok(pack) } else { Err(errors) }
+}
+
+// Add a pack's content; a reused id replaces the earlier entry
+fn merge(content: &mut Content, pack: Content) {
+    fn upsert<T>(list: &mut Vec<T>, new: Vec<T>, id: fn(&T) -> &str) {
+        for item in new {
+            match list.iter().position(|old| id(old) == id(&item)) {
+                Some(i) => list[i] = item,
+                None => list.push(item),
+            }
+        }
+    }
+    content.packs.extend(pack.packs);
+    content.profiles = pack.profiles;
+    upsert(&mut content.nodes, pack.nodes, |n| &n.id);
+    upsert(&mut content.items, pack.items, |i| &i.id);
+    upsert(&mut content.events, pack.events, |e| &e.id);
+    upsert(&mut content.hints, pack.hints, |h| &h.id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Write a pack to a scratch file and validate it against the built-in content
+    fn check_pack(name: &str, text: &str, loaded: &Content) -> Result<Content, Vec<String>> {
+        let path = std::env::temp_dir().join(format!("cyberheist-test-{}-{}.txt", std::process::id(), name));
+        fs::write(&path, text).unwrap();
+        let result = load_pack(&path, loaded);
+        let _ = fs::remove_file(&path);
+        result
+    }
+
+    #[test]
+    fn schema_errors_skip_the_pack() {
+        let cases = [
+            ("section", "pack.name=x\nvault.a.name=y\n", "unknown section 'vault'"),
+            ("field", "pack.name=x\nitem.kit.colour=red\n", "unknown field 'colour' for item"),
+            ("shape", "pack.name=x\nitem.kit=y\n", "expected item.ID.FIELD"),
+            ("pack_id", "pack.main.name=x\n", "expected pack.FIELD"),
+            ("difficulty_id", "pack.name=x\ndifficulty.insane.lock_timer=10\n", "difficulty must be one of easy, normal, hard"),
+            ("timer_low", "pack.name=x\ndifficulty.easy.lock_timer=4\n", "must be a whole number from 5 to 99"),
+            ("honeypots_word", "pack.name=x\ndifficulty.hard.honeypots=lots\n", "must be a whole number from 0 to 100"),
+            ("node_range", "pack.name=x\ndifficulty.normal.node_min=80\ndifficulty.normal.node_max=10\n", "has node_min above node_max"),
+            ("flag", "pack.name=x\nitem.kit.name=Kit\nitem.kit.start=maybe\n", "must be yes or no"),
+            ("choice", "pack.name=x\nhint.h.on=firewall\nhint.h.text=t\n", "must be one of honeypot, exploit, clear"),
+            ("twice", "pack.name=x\nitem.kit.name=Kit\nitem.kit.name=Other\n", "name is set twice"),
+            ("missing", "pack.name=x\nnode.relay.name=Relay\n", "node.relay is missing share, min, max"),
+            ("no_name", "item.kit.name=Kit\n", "pack.name is required"),
+            ("no_equals", "pack.name=x\nitem.kit.name\n", "expected key=value"),
+            ("shares", "pack.name=x\nnode.a.name=A\nnode.a.share=101\nnode.a.min=1\nnode.a.max=2\n", "from 1 to 100"),
+        ];
+        let loaded = Content::default();
+        for (name, text, expected) in cases {
+            let errors = check_pack(name, text, &loaded).expect_err(name);
+            assert!(errors.iter().any(|e| e.contains(expected)), "{}: {:?}", name, errors);
+        }
+    }
+
+    #[test]
+    fn shares_count_the_packs_before() {
+        let earlier = check_pack("first", "pack.name=a\nnode.a.name=A\nnode.a.share=60\nnode.a.min=1\nnode.a.max=2\n", &Content::default()).unwrap();
+        let mut loaded = Content::default();
+        merge(&mut loaded, earlier);
+        let errors = check_pack("second", "pack.name=b\nnode.b.name=B\nnode.b.share=50\nnode.b.min=1\nnode.b.max=2\n", &loaded).unwrap_err();
+        assert!(errors.iter().any(|e| e.contains("add up to 110%")), "{:?}", errors);
+    }
+
+    #[test]
+    fn reused_ids_merge_within_a_pack_and_replace_across_packs() {
+        let first = check_pack("one", "pack.name=one\nitem.kit.name=Kit\n# comment\n\nitem.kit.chance=20\ndifficulty.easy.lock_timer=30\n", &Content::default()).unwrap();
+        assert_eq!(first.items.len(), 1);
+        assert_eq!(first.items[0].chance, 20);
+
+        let mut content = Content::default();
+        merge(&mut content, first);
+        let second = check_pack("two", "pack.name=two\nitem.kit.name=Better kit\n", &content).unwrap();
+        merge(&mut content, second);
+        assert_eq!(content.packs, vec!["one".to_string(), "two".to_string()]);
+        assert_eq!(content.items.len(), 1);
+        assert_eq!(content.items[0].name, "Better kit");
+        assert_eq!(content.profile(Difficulty::Easy).lock_timer, 30);
+    }
+}
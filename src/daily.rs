@@ -0,0 +1,293 @@
+/*
+DATA TYPES USED:
+
+1. Daily            -> Struct: the date's seed, difficulty and vault, the same for everyone
+2. DailyResults     -> Struct: attempts, best score and wins per day, saved to disk
+3. day              -> u64, days since 1970-01-01 (UTC)
+4. date             -> String, "YYYY-MM-DD" label for a day
+5. weekday          -> u64, 0 = Sunday .. 6 = Saturday
+6. seed             -> u64, number the mission and board are built from
+7. rng              -> StdRng, seeded random number generator
+8. kinds            -> Vec<LockKind>, vault locks, outermost first
+9. record           -> Record, key=value pairs in the results file
+10. streak          -> u32, days in a row with a successful run
+11. tr!             -> macro (from i18n), message text in the chosen language
+*/
+
+use std::io;                             // Error type
+use std::path::PathBuf;                  // File paths
+use std::time::{SystemTime, UNIX_EPOCH}; // Today's date
+
+use rand::rngs::StdRng;     // Seeded generator
+use rand::seq::SliceRandom; // Pick lock kinds
+use rand::SeedableRng;
+
+use crate::engine::RuleOptions; // Rule overrides
+use crate::security::code::generate_code_with; // Seeded vault code
+use crate::security::firewall::{create_firewall_grid_with, FirewallNode}; // Seeded firewall
+use crate::security::vault::{LockKind, Vault}; // Vault lock kinds and seeded vault
+use crate::security::Difficulty; // Difficulty enum
+use crate::storage::{self, Record}; // Saved data
+use crate::utils::set_count; // Update a saved counter
+use crate::i18n::tr; // Translated text
+
+// Mixed into the day number so daily seeds don't look like dates
+const SEED_SALT: u64 = 0x00C0_FFEE_D411_7000;
+
+// Locks the daily vault can add behind the code lock
+const EXTRA_LOCKS: [LockKind; 6] = [
+    LockKind::Pattern,
+    LockKind::Timing,
+    LockKind::Password,
+    LockKind::Dial,
+    LockKind::Cipher,
+    LockKind::Lights,
+];
+
+// Struct describing one day's challenge
+#[derive(Debug, Clone)]
+pub struct Daily {
+    pub day: u64,               // Days since 1970-01-01
+    pub date: String,           // "YYYY-MM-DD"
+    pub seed: u64,              // Mission and board seed
+    pub difficulty: Difficulty, // Difficulty for the day of the week
+    pub vault: Vec<LockKind>,   // Vault locks, outermost first
+}
+
+// Struct holding every recorded daily run on this machine
+pub struct DailyResults {
+    record: Record, // "<date>.attempts", "<date>.best", "<date>.wins"
+}
+
+// Today's day number (UTC, so the whole team shares one date)
+pub fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() / 86_400)
+        .unwrap_or(0)
+}
+
+// "YYYY-MM-DD" for a day number (civil calendar)
+pub fn date_label(day: u64) -> String {
+    let z = day as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097); // Day of the 400-year era
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365; // Year of the era
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // Day of the year, from March
+    let mp = (5 * doy + 2) / 153; // Month, from March
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", y, m, d)
+}
+
+impl Daily {
+    // The challenge for a given day
+    pub fn for_day(day: u64) -> Self {
+        let seed = (day ^ SEED_SALT).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+
+        // Easy early in the week, hard on Friday and Saturday
+        let weekday = (day + 4) % 7; // 1970-01-01 was a Thursday
+        let difficulty = match weekday {
+            1 | 2 => Difficulty::Easy,
+            5 | 6 => Difficulty::Hard,
+            _ => Difficulty::Normal,
+        };
+
+        // The code lock first, then the day's extra locks
+        let mut rng = StdRng::seed_from_u64(seed);
+        let extra = match difficulty {
+            Difficulty::Easy => 0,
+            Difficulty::Normal => 1,
+            Difficulty::Hard => 2,
+        };
+        let mut vault = vec![LockKind::Code];
+        vault.extend(EXTRA_LOCKS.choose_multiple(&mut rng, extra).copied());
+
+        Daily {
+            day,
+            date: date_label(day),
+            seed,
+            difficulty,
+            vault,
+        }
+    }
+
+    // Today's challenge
+    pub fn today() -> Self {
+        Daily::for_day(today())
+    }
+
+    // Rule overrides for the day: the mission seed (the vault comes from `vault`)
+    pub fn rules(&self) -> RuleOptions {
+        RuleOptions {
+            seed: Some(self.seed),
+            ..RuleOptions::default()
+        }
+    }
+
+    // The day's vault code and firewall
+    pub fn board(&self, rows: usize, cols: usize) -> ([u8; 4], Vec<FirewallNode>) {
+        let mut rng = StdRng::seed_from_u64(self.seed.rotate_left(17));
+        let code = generate_code_with(&mut rng);
+        (code, create_firewall_grid_with(&mut rng, rows, cols, self.difficulty))
+    }
+
+    // The day's vault, every lock's secret included
+    pub fn vault(&self, rows: usize, cols: usize) -> Vault {
+        let mut rng = StdRng::seed_from_u64(self.seed.rotate_left(29));
+        Vault::new_with(&mut rng, &self.vault, self.difficulty, rows, cols)
+    }
+
+    // Generator for the day's re-keys, so the code changes the same way for everyone
+    pub fn rekey_rng(&self) -> StdRng {
+        StdRng::seed_from_u64(self.seed.rotate_left(41))
+    }
+
+    // Header shown before the run
+    pub fn header_lines(&self) -> Vec<String> {
+        let locks: Vec<String> = self.vault.iter().map(|k| format!("{:?}", k).to_lowercase()).collect();
+        vec![tr!("daily.title", self.date), tr!("daily.rules", self.difficulty.label(), locks.join(", "))]
+    }
+}
+
+// Where daily results are kept
+fn results_path() -> PathBuf {
+    storage::data_dir().join("daily.txt")
+}
+
+impl DailyResults {
+    // Load the results file (missing file = no runs yet)
+    pub fn load() -> io::Result<Self> {
+        Ok(DailyResults {
+            record: storage::read_record(&results_path())?,
+        })
+    }
+
+    // Save the results file
+    pub fn save(&self) -> io::Result<()> {
+        storage::write_record(&results_path(), &self.record)
+    }
+
+    // Look up one figure for a day
+    fn value(&self, date: &str, field: &str) -> u32 {
+        storage::get_u32(&self.record, &format!("{}.{}", date, field))
+    }
+
+    // Overwrite one figure for a day
+    fn set(&mut self, date: &str, field: &str, value: u32) {
+        set_count(&mut self.record, &format!("{}.{}", date, field), value);
+    }
+
+    // Add a finished run to a day
+    pub fn record(&mut self, date: &str, score: u32, won: bool) {
+        self.set(date, "attempts", self.value(date, "attempts") + 1);
+        self.set(date, "best", self.value(date, "best").max(score));
+        if won {
+            self.set(date, "wins", self.value(date, "wins") + 1);
+        }
+    }
+
+    // Days in a row with a win, ending today (or yesterday, if today is not won yet)
+    pub fn streak(&self, day: u64) -> u32 {
+        let won = |d: u64| self.value(&date_label(d), "wins") > 0;
+        let mut d = if won(day) { day } else { day.saturating_sub(1) };
+        let mut streak = 0;
+        while won(d) {
+            streak += 1;
+            if d == 0 {
+                break;
+            }
+            d -= 1;
+        }
+        streak
+    }
+
+    // Attempts, best score and streak for a day
+    pub fn summary_lines(&self, daily: &Daily) -> Vec<String> {
+        let attempts = self.value(&daily.date, "attempts");
+        if attempts == 0 {
+            return vec![tr!("daily.no_attempts", self.streak(daily.day))];
+        }
+        vec![tr!(
+            "daily.today",
+            attempts,
+            self.value(&daily.date, "wins"),
+            self.value(&daily.date, "best"),
+            self.streak(daily.day)
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::rotation::pick_positions;
+
+    const NEW_YEAR_2024: u64 = 19_723; // 2024-01-01
+
+    // Results with a win on each listed day
+    fn wins_on(days: &[u64]) -> DailyResults {
+        let mut results = DailyResults { record: Record::new() };
+        for &day in days {
+            results.record(&date_label(day), 100, true);
+        }
+        results
+    }
+
+    #[test]
+    fn dates_cross_month_and_year_ends() {
+        assert_eq!(date_label(0), "1970-01-01");
+        assert_eq!(date_label(NEW_YEAR_2024 - 1), "2023-12-31");
+        assert_eq!(date_label(NEW_YEAR_2024), "2024-01-01");
+        assert_eq!(date_label(NEW_YEAR_2024 + 30), "2024-01-31");
+        assert_eq!(date_label(NEW_YEAR_2024 + 31), "2024-02-01");
+        assert_eq!(date_label(NEW_YEAR_2024 + 59), "2024-02-29");
+        assert_eq!(date_label(NEW_YEAR_2024 + 60), "2024-03-01");
+    }
+
+    #[test]
+    fn streak_runs_across_a_year_end() {
+        let day = NEW_YEAR_2024 + 1;
+        let results = wins_on(&[NEW_YEAR_2024 - 2, NEW_YEAR_2024 - 1, NEW_YEAR_2024, day]);
+        assert_eq!(results.streak(day), 4);
+        assert_eq!(results.streak(day + 1), 4); // Today not won yet
+        assert_eq!(results.streak(day + 2), 0); // Yesterday missed
+    }
+
+    #[test]
+    fn missed_day_or_loss_breaks_the_streak() {
+        let feb = NEW_YEAR_2024 + 31;
+        let mut results = wins_on(&[feb - 3, feb - 1, feb]);
+        assert_eq!(results.streak(feb), 2);
+
+        results.record(&date_label(feb + 1), 40, false);
+        assert_eq!(results.streak(feb + 1), 2);
+        assert_eq!(results.streak(feb + 2), 0);
+        assert_eq!(results.value(&date_label(feb + 1), "attempts"), 1);
+        assert_eq!(results.value(&date_label(feb + 1), "wins"), 0);
+    }
+
+    #[test]
+    fn same_date_gives_the_same_challenge() {
+        for day in [NEW_YEAR_2024, NEW_YEAR_2024 + 4, NEW_YEAR_2024 + 5] {
+            let (a, b) = (Daily::for_day(day), Daily::for_day(day));
+            assert_eq!(a.seed, b.seed);
+            assert_eq!(a.difficulty, b.difficulty);
+            assert_eq!(a.vault, b.vault);
+
+            let (code_a, fw_a) = a.board(5, 5);
+            let (code_b, fw_b) = b.board(5, 5);
+            assert_eq!(code_a, code_b);
+            assert_eq!(format!("{:?}", fw_a), format!("{:?}", fw_b));
+            assert_eq!(format!("{:?}", a.vault(5, 5)), format!("{:?}", b.vault(5, 5)));
+
+            let rekeys = |d: &Daily| -> Vec<Vec<usize>> {
+                let mut rng = d.rekey_rng();
+                (0..8).map(|_| pick_positions(&mut rng, 2)).collect()
+            };
+            assert_eq!(rekeys(&a), rekeys(&b));
+        }
+        assert_ne!(Daily::for_day(NEW_YEAR_2024).seed, Daily::for_day(NEW_YEAR_2024 + 1).seed);
+    }
+}
@@ -0,0 +1,153 @@
+/*
+DATA TYPES USED:
+
+1. EnergyState      -> Enum: Drained, Nominal, Overcharged
+2. energy, rated    -> i32, current energy and the operator's normal maximum
+3. owned            -> usize, firewall nodes the operator holds (they feed regen)
+4. regen, bleed     -> i32, energy restored / lost at the end of a turn
+5. log              -> &mut Vec<String>, messages for the acting operator
+6. difficulty       -> Difficulty enum: Easy, Normal, Hard
+7. item             -> &Item (from content), a content-pack item that may restore energy
+8. tr!              -> macro (from i18n), message text in the chosen language
+*/
+
+use rand::Rng; // Random number generator
+
+use crate::player::{OperatorClass, Player, ENGINEER_BATTERY_CHANCE}; // Operator whose energy changes
+use crate::security::Difficulty; // Difficulty enum
+use crate::content; // Content-pack items
+use crate::i18n::tr; // Translated text
+
+// Cost of each action, in energy
+pub const SCAN_SWEEP: i32 = 8;     // Broad scan
+pub const SCAN_CODE: i32 = 10;     // First targeted code scan
+pub const SCAN_GRID: i32 = 6;      // First targeted grid scan
+pub const SCAN_STEP: i32 = 2;      // Added per earlier targeted scan of the same kind
+pub const CODE_GUESS: i32 = 6;     // Code lock attempt
+pub const BRUTE_FORCE: i32 = 30;   // Brute force on the code lock
+pub const PATTERN_TRACE: i32 = 5;  // Pattern lock attempt
+pub const TIMING_SYNC: i32 = 4;    // Timing lock attempt
+pub const PASSWORD_GUESS: i32 = 5; // Password lock attempt
+pub const DIAL_TURN: i32 = 3;      // Dial lock attempt
+pub const CIPHER_GUESS: i32 = 4;   // Cipher lock attempt
+pub const LIGHTS_PRESS: i32 = 3;   // Lights lock attempt
+
+// Hard ceiling, reachable only by overcharging
+pub const OVERCHARGE_CAP: i32 = 150;
+
+// Energy lost per turn while overcharged
+pub const OVERCHARGE_BLEED: i32 = 3;
+
+// At or below this, the operator is drained
+pub const DRAINED_AT: i32 = 20;
+
+// Energy drawn from a node when it is compromised (may overcharge)
+pub const SIPHON: i32 = 4;
+
+// Battery pack: chance per turn and energy restored (may overcharge)
+const BATTERY_CHANCE: f64 = 0.07;
+const BATTERY_BOOST: i32 = 8;
+
+// Enum for how well-powered an operator is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnergyState {
+    Drained,     // Running low: hacks are sloppier
+    Nominal,     // Normal operation
+    Overcharged, // Above the normal maximum: hacks are sharper, energy bleeds off
+}
+
+impl EnergyState {
+    // Work out the state from current and rated energy
+    pub fn of(energy: i32, rated: i32) -> Self {
+        if energy > rated {
+            EnergyState::Overcharged
+        } else if energy <= DRAINED_AT {
+            EnergyState::Drained
+        } else {
+            EnergyState::Nominal
+        }
+    }
+
+    // Change to hack success chance, in percentage points
+    pub fn hack_bonus(&self) -> i32 {
+        match self {
+            EnergyState::Drained => -10,
+            EnergyState::Nominal => 0,
+            EnergyState::Overcharged => 10,
+        }
+    }
+
+    // Tag shown next to the energy figure
+    pub fn tag(&self) -> String {
+        match self {
+            EnergyState::Drained => format!(" ({})", tr!("energy.drained")),
+            EnergyState::Nominal => String::new(),
+            EnergyState::Overcharged => format!(" ({})", tr!("energy.overcharged")),
+        }
+    }
+}
+
+// Starting (and normal maximum) energy for a difficulty
+pub fn rated_energy(difficulty: Difficulty) -> i32 {
+    match difficulty {
+        Difficulty::Easy => 120,
+        Difficulty::Normal => 100,
+        Difficulty::Hard => 80,
+    }
+}
+
+// Energy cost of hacking a node of this difficulty
+pub fn hack_cost(node_difficulty: u8) -> i32 {
+    node_difficulty as i32 / 10 + 5
+}
+
+// Energy cost of the next targeted scan after `done` earlier ones
+pub fn targeted_scan_cost(base: i32, done: u32) -> i32 {
+    base + SCAN_STEP * done as i32
+}
+
+// Passive regeneration per turn: a trickle by difficulty, plus 1 per 3 nodes held
+pub fn passive_regen(difficulty: Difficulty, owned: usize) -> i32 {
+    let base = match difficulty {
+        Difficulty::Easy => 3,
+        Difficulty::Normal => 2,
+        Difficulty::Hard => 1,
+    };
+    base + owned as i32 / 3
+}
+
+// Add energy that may push the operator into overcharge
+pub fn boost(player: &mut Player, amount: i32) {
+    player.energy = (player.energy + amount).min(OVERCHARGE_CAP);
+}
+
+// End-of-turn energy rules: overcharge bleeds off, otherwise regen up to the rated maximum
+pub fn end_of_turn(player: &mut Player, difficulty: Difficulty, owned: usize, log: &mut Vec<String>) {
+    if player.energy > player.rated_energy {
+        let bleed = OVERCHARGE_BLEED.min(player.energy - player.rated_energy);
+        player.energy -= bleed;
+        log.push(tr!("energy.bleed", bleed));
+    } else if player.energy > 0 {
+        let regen = passive_regen(difficulty, owned).min(player.rated_energy - player.energy);
+        player.energy += regen;
+    }
+
+    let chance = if player.is(OperatorClass::Engineer) { ENGINEER_BATTERY_CHANCE } else { BATTERY_CHANCE };
+    if player.inventory.iter().any(|item| item == "Battery pack")
+        && rand::thread_rng().gen_bool(chance)
+    {
+        boost(player, BATTERY_BOOST);
+        log.push(tr!("energy.battery"));
+    }
+
+    // Content-pack items held by the operator
+    for item in &content::get().items {
+        if item.chance > 0
+            && player.inventory.contains(&item.name)
+            && rand::thread_rng().gen_range(0..100) < item.chance
+        {
+            boost(player, item.energy);
+            log.push(item.text.clone());
+        }
+    }
+}
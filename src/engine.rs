@@ -0,0 +1,1604 @@
+/*
+DATA TYPES USED:
+
+1. GameResult       -> Enum: Success, Failure(String)
+2. PlayMode         -> Enum: Competitive, Cooperative
+3. Seat             -> Struct: one operator's player, notes and progress
+4. Outcome          -> Struct: lines for the actor, announcements for the others, turn flag
+5. Heist            -> Struct: complete game state and the rules that change it
+6. seats            -> Vec<Seat>, every operator in the heist (1-4)
+7. turn             -> usize, index of the seat whose turn it is
+8. out              -> Option<String>, reason an operator left the heist
+9. result           -> Option<GameResult>, set once the heist is decided
+10. log             -> Vec<String>, messages produced by security functions
+11. start_time      -> Instant, game start time
+12. action          -> Action enum, what the operator wants to do
+13. r, c, idx       -> usize, node coordinates and index in firewall vector
+14. reveal_roll, pos -> u32 / usize, random numbers for data leaks
+15. defender        -> Option<Defender>, the sysadmin role if one is playing
+16. defending       -> bool, whether the sysadmin is taking their turn
+17. mv              -> DefenseMove enum, sysadmin move
+18. RuleOptions     -> Struct: optional rule overrides from the command line
+19. epoch           -> u32, vault re-keys so far, stamped on new notes
+20. locks_open      -> usize, vault locks an operator has opened, outermost first
+21. layer, lock     -> usize / Lock, the vault lock an attempt is aimed at
+22. input           -> String, text typed after "guess", read by the current lock
+23. costs           -> Vec<String>, energy forecast for the actions open to the operator
+24. victors         -> Vec<usize>, seats that share the win, for XP awards
+25. xp              -> u32, experience an operator earned this mission
+26. mission         -> Mission struct, seeded codename, briefing and optional objectives
+27. explosions      -> u32, nodes an operator has blown up
+28. objective       -> Objective enum, a primary or optional goal
+29. progress        -> Progress enum: Open(note), Done, Failed
+30. code_guesses    -> u32, code lock attempts an operator has made
+31. cracked_in      -> Option<u32>, code guesses it took to open the code lock
+32. score           -> u32, points for objectives met and time left
+33. amount          -> u32, alert an action raises (quiet scans to very loud brute force)
+34. history         -> Vec<Snapshot>, practice-mode copies of the state at the start of each turn
+35. steps           -> usize, turns an undo rewinds
+36. achievements    -> Vec<Achievement>, feats an operator has unlocked (from their profile, plus this run)
+37. tally           -> Tally struct, successes and action counts for lifetime stats
+38. style           -> Style struct, color theme and screen-reader option for maps and tags
+39. script          -> Option<MissionScript>, Rhai hooks loaded with --script
+40. hooked          -> Option<u32>, the turn whose on_turn_start hook has already run
+41. feedback        -> rhai Map, a lock's answer to a guess, passed to on_guess
+42. route           -> Option<(usize, usize)>, target of the operator's queued route
+43. plan            -> Route struct (from security::route), nodes to hack, energy and turns
+44. probe           -> Option<(usize, usize)>, node whose hack odds to show (None: the whole grid)
+45. tr!             -> macro (from i18n), message text in the chosen language
+*/
+
+use std::str::FromStr;  // Parse play mode names
+use std::time::Instant; // For timing the game
+
+use rand::Rng; // Random number generator
+
+use crate::achievements::{Achievement, ACHIEVEMENTS, MASTERMIND_GUESSES}; // Unlockable feats
+use crate::actions::Action; // Enum for player actions
+use crate::i18n::tr; // Translated text
+use crate::energy::{self, EnergyState}; // Cost table and energy rules
+use crate::mission::Mission; // Generated mission
+use crate::objectives::{self, holding, Objective, Progress}; // Primary and optional objectives
+use crate::notebook::Notebook; // Guess history and deduction
+use crate::player::{OperatorClass, Player, CRACKER_GUESS_DISCOUNT}; // Player struct and class perks
+use crate::security::code::{mastermind_feedback, parse_code, CodeClue}; // Code functions
+use crate::security::defense::{self, Defender, DefenderKind, DefenseMove}; // Sysadmin role
+use crate::security::firewall::{attempt_hack_node, best_target, hack_price, in_reach, probe_lines, render_difficulty_map, render_firewall_map, render_odds_map, FirewallNode, NodeState}; // Firewall functions
+use crate::security::hints::ScanTarget; // Scan targeting
+use crate::security::puzzle::PuzzleContext; // Game state for vault puzzles
+use crate::security::route::{self, Route}; // Cheapest hacking routes
+use crate::security::vault::{Lock, LockKind, Vault}; // Staged vault locks
+use crate::security::{Difficulty, SecuritySystem}; // Security system and difficulty
+use crate::security::noise::{self, Detection}; // Action noise and detection bands
+use crate::script::{node_map, Hook, MissionScript}; // Mission script hooks
+use crate::stats::{Run, Tally}; // Lifetime stats
+use crate::theme::{Role, Style, Theme}; // Color themes
+use crate::ui::help_lines; // Shared help text
+
+// Firewall grid size
+pub const GRID_ROWS: usize = 5;
+pub const GRID_COLS: usize = 5;
+
+// Objectives every mission shares; meeting all of them wins the heist
+pub const PRIMARY: [Objective; 2] = [Objective::ControlFirewall, Objective::OpenVault];
+
+// Maximum number of operators in one heist
+pub const MAX_OPERATORS: usize = 4;
+
+// Enum for game outcome
+#[derive(Debug, Clone)]
+pub enum GameResult {
+    Success,                // Player wins
+    Failure(String),        // Player loses with message
+}
+
+// Enum for how several operators relate to each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayMode {
+    Competitive, // First operator to meet the objective on their own wins
+    Cooperative, // The crew shares notes, nodes and the win
+}
+
+// Convert "versus" / "coop" etc. into PlayMode
+impl FromStr for PlayMode {
+    type Err = (); // Unit type () used as error
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "v" | "versus" | "competitive" => Ok(PlayMode::Competitive),
+            "c" | "coop" | "cooperative" => Ok(PlayMode::Cooperative),
+            _ => Err(()),
+        }
+    }
+}
+
+impl PlayMode {
+    // Name in the chosen language
+    pub fn label(&self) -> String {
+        match self {
+            PlayMode::Competitive => tr!("mode.competitive"),
+            PlayMode::Cooperative => tr!("mode.cooperative"),
+        }
+    }
+}
+
+// Rule overrides chosen at launch (None keeps the difficulty default)
+#[derive(Debug, Clone, Default)]
+pub struct RuleOptions {
+    pub rotate_every: Option<u32>,   // Turns between scheduled re-keys (0 = never)
+    pub rotate_alert: Option<u32>,   // Alert level that forces a re-key (0 = never)
+    pub rotate_digits: Option<usize>, // Digits changed by one re-key (1-4)
+    pub vault: Option<Vec<LockKind>>, // Vault locks, outermost first
+    pub seed: Option<u64>,            // Mission seed (random if None)
+    pub practice: bool,               // Snapshot every turn and allow undo
+    pub theme: Option<Theme>,         // Terminal color theme (default: classic, or mono with NO_COLOR)
+    pub screen_reader: bool,          // Describe the grid in words
+    pub script: Option<String>,       // Mission script file (Rhai)
+    pub edge_reach: bool,             // Only edge nodes and nodes next to a foothold can be hacked
+}
+
+// Struct holding everything private to one operator
+#[derive(Clone)]
+pub struct Seat {
+    pub player: Player,       // Operator stats and inventory
+    pub notebook: Notebook,   // Notes on guesses and clues
+    pub locks_open: usize,    // Vault locks this operator has opened so far
+    pub explosions: u32,      // Nodes this operator has blown up
+    pub code_guesses: u32,    // Code lock attempts so far
+    pub cracked_in: Option<u32>, // Code guesses it took to open the code lock
+    pub brute_cracked: bool,  // The code lock was opened by brute force
+    pub achievements: Vec<Achievement>, // Unlocked feats, loaded from the profile
+    pub tally: Tally,         // Successes and action counts this run
+    pub route: Option<(usize, usize)>, // Target of the queued route, if any
+    pub out: Option<String>,  // Reason the operator left the heist, if any
+}
+
+// Text produced by one step of the engine
+#[derive(Debug, Default)]
+pub struct Outcome {
+    pub lines: Vec<String>,    // Shown to the operator who acted
+    pub announce: Vec<String>, // Shown to every other operator
+    pub turn_over: bool,       // Whether the action used up the turn
+}
+
+impl Outcome {
+    // Add a line everyone should see
+    fn say_all(&mut self, text: String) {
+        self.announce.push(text.clone());
+        self.lines.push(text);
+    }
+}
+
+// State saved at the start of a turn in practice mode
+#[derive(Clone)]
+struct Snapshot {
+    taken: u32,                 // Turns taken when it was saved
+    system: SecuritySystem,     // Security system
+    seats: Vec<Seat>,           // Every operator
+    turn: usize,                // Seat whose turn it was
+    defender: Option<Defender>, // Sysadmin
+    script: Option<rhai::Map>,  // Mission script variables
+    hooked: Option<u32>,        // Turn whose on_turn_start hook had run
+}
+
+// Start-of-turn snapshots practice mode keeps (the newest is the current turn)
+pub const UNDO_DEPTH: usize = 30;
+
+// Struct holding the whole heist: rules only, no terminal or network I/O
+pub struct Heist {
+    pub system: SecuritySystem, // Shared security system
+    pub seats: Vec<Seat>,       // Operators in seat order
+    pub turn: usize,            // Seat whose turn it is
+    pub mode: PlayMode,         // Competitive or cooperative
+    pub rows: usize,            // Firewall rows
+    pub cols: usize,            // Firewall columns
+    pub defender: Option<Defender>, // Sysadmin, if one is playing
+    pub mission: Mission,       // Codename, briefing and optional objectives
+    defending: bool,            // Sysadmin is taking their turn
+    start_time: Instant,        // Start timer
+    result: Option<GameResult>, // Set once the heist is decided
+    over: bool,                 // The heist has been decided (kept after the result is taken)
+    victors: Vec<usize>,        // Seats that share the win
+    practice: bool,             // Practice mode: turns can be undone
+    edge_reach: bool,           // Hacks need an edge node or a foothold next door
+    history: Vec<Snapshot>,     // Start-of-turn snapshots, oldest first
+    turns_taken: u32,           // Attacker turns completed
+    rewound: bool,              // Undo was used, so the run is not recorded
+    pub style: Style,           // How maps and tags are drawn (plain unless a terminal front end sets it)
+    script: Option<MissionScript>, // Mission script hooks, if one was loaded
+    hooked: Option<u32>,        // Turn whose on_turn_start hook has run
+}
+
+impl Heist {
+    // Constructor: one seat per name, shared security system
+    pub fn new(names: &[String], difficulty: Difficulty, mode: PlayMode, defender: Option<Defender>) -> Self {
+        let seats: Vec<Seat> = names
+            .iter()
+            .enumerate()
+            .map(|(id, name)| Seat {
+                player: Player::new(id, name, difficulty),
+                notebook: Notebook::new(),
+                locks_open: 0,
+                explosions: 0,
+                code_guesses: 0,
+                cracked_in: None,
+                brute_cracked: false,
+                achievements: Vec::new(),
+                tally: Tally::default(),
+                route: None,
+                out: None,
+            })
+            .collect();
+
+        // Every operator's action ticks the shared timer
+        let mut system = SecuritySystem::new(difficulty, GRID_ROWS, GRID_COLS);
+        system.lock_timer *= seats.len() as i32;
+
+        let mut heist = Heist {
+            system,
+            seats,
+            turn: 0,
+            mode,
+            rows: GRID_ROWS,
+            cols: GRID_COLS,
+            defender,
+            mission: Mission::random(difficulty, GRID_ROWS, GRID_COLS),
+            defending: false,
+            start_time: Instant::now(),
+            result: None,
+            over: false,
+            victors: Vec::new(),
+            practice: false,
+            edge_reach: false,
+            history: Vec::new(),
+            turns_taken: 0,
+            rewound: false,
+            style: Style::default(),
+            script: None,
+            hooked: None,
+        };
+        heist.fit_mission();
+        heist
+    }
+
+    // Fit the mission to this heist: data stores are real nodes, code goals need a code lock
+    fn fit_mission(&mut self) {
+        for &(r, c) in self.mission.active_data_stores() {
+            self.system.firewall[r * self.cols + c].honeypot = false;
+        }
+        if self.system.vault.code_layer().is_none() {
+            self.mission.drop_code_objectives();
+        }
+    }
+
+    // Replace the random board with a fixed code and firewall (the tutorial)
+    pub fn stage(&mut self, code: [u8; 4], firewall: Vec<FirewallNode>) {
+        self.system.code_digits = code;
+        self.system.firewall = firewall;
+        self.fit_mission();
+    }
+
+    // Attach a loaded mission script
+    pub fn set_script(&mut self, script: MissionScript) {
+        self.script = Some(script);
+    }
+
+    // Run a script hook for one operator (nothing without a script)
+    fn hook(&mut self, seat: usize, hook: Hook) -> Vec<String> {
+        match &self.script {
+            Some(script) => script.run(hook, &mut self.seats[seat].player, &mut self.system),
+            None => Vec::new(),
+        }
+    }
+
+    // Apply rule overrides chosen at launch
+    pub fn apply_rules(&mut self, rules: &RuleOptions) {
+        let policy = &mut self.system.rotation;
+        if let Some(n) = rules.rotate_every {
+            policy.every_turns = n;
+        }
+        if let Some(n) = rules.rotate_alert {
+            policy.alert_threshold = n;
+        }
+        if let Some(n) = rules.rotate_digits {
+            policy.digits = n;
+        }
+        if let Some(kinds) = &rules.vault {
+            self.system.vault = Vault::new(kinds, self.system.difficulty, self.rows, self.cols);
+        }
+        self.practice = rules.practice;
+        self.edge_reach = rules.edge_reach;
+        if let Some(seed) = rules.seed {
+            self.mission = Mission::generate(seed, self.system.difficulty, self.rows, self.cols);
+        }
+        self.fit_mission();
+    }
+
+    // More than one operator at the table
+    pub fn multi(&self) -> bool {
+        self.seats.len() > 1
+    }
+
+    // Welcome text shown once at the start
+    pub fn intro_lines(&self) -> Vec<String> {
+        let mut lines = vec![String::new()];
+        lines.extend(self.mission.briefing_lines());
+        lines.push(String::new());
+        for seat in &self.seats {
+            lines.push(tr!("heist.welcome", seat.player.title(), seat.player.energy));
+        }
+        lines.push(match (self.multi(), self.mode) {
+            (false, _) => tr!("heist.goal_solo"),
+            (true, PlayMode::Competitive) => tr!("heist.goal_versus"),
+            (true, PlayMode::Cooperative) => tr!("heist.goal_coop"),
+        });
+        lines.push(tr!("heist.vault_locks", self.system.vault.locks.len()));
+        lines.extend(self.lock_lines(0));
+        if let Some(d) = &self.defender {
+            let kind = if d.kind == DefenderKind::Ai { tr!("heist.sysadmin_ai") } else { tr!("heist.sysadmin_human") };
+            lines.push(tr!("heist.sysadmin_intro", d.name, kind));
+        }
+        if let Some(script) = &self.script {
+            lines.push(tr!("heist.script", script.path, script.hooks().join(", ")));
+            lines.extend(script.intro.iter().cloned());
+        }
+        lines.push(tr!("heist.help_hint"));
+        lines.push(String::new());
+        lines
+    }
+
+    // Whether the sysadmin is taking their turn
+    pub fn defending(&self) -> bool {
+        self.defending
+    }
+
+    // Index of whoever must act now: the seat, or seats.len() for the sysadmin
+    pub fn actor(&self) -> usize {
+        if self.defending { self.seats.len() } else { self.turn }
+    }
+
+    // Name of whoever must act now
+    pub fn actor_name(&self) -> String {
+        match &self.defender {
+            Some(d) if self.defending => d.name.clone(),
+            _ => self.seats[self.turn].player.name.clone(),
+        }
+    }
+
+    // Record a loss for the attackers (a win for the sysadmin, if any)
+    fn fail(&mut self, out: &mut Outcome, reason: &str) {
+        if let Some(d) = &self.defender {
+            out.say_all(tr!("heist.sysadmin_holds", d.name));
+        }
+        self.result = Some(GameResult::Failure(reason.to_string()));
+        self.over = true;
+        for line in self.debrief_lines() {
+            out.say_all(line);
+        }
+    }
+
+    // Current vault lock for an operator
+    pub fn lock_progress(&self, seat: usize) -> String {
+        self.system.vault.progress(self.seats[seat].locks_open)
+    }
+
+    // Current vault lock with everything the operator can see of it
+    pub fn lock_lines(&self, seat: usize) -> Vec<String> {
+        let mut lines = vec![tr!("heist.vault", self.lock_progress(seat))];
+        if let Some(Lock::Puzzle(puzzle)) = self.system.vault.locks.get(self.seats[seat].locks_open) {
+            lines.extend(puzzle.reveal_lines(&self.puzzle_context(seat)));
+        }
+        lines
+    }
+
+    // Detection band tag, colored by the theme
+    fn detection_tag(&self) -> String {
+        let detection = self.system.detection();
+        match detection {
+            Detection::Calm => detection.label(),
+            Detection::Wary => self.style.theme.paint(&detection.label(), Role::Warning),
+            Detection::Alarmed => self.style.theme.paint(&detection.label(), Role::Danger),
+        }
+    }
+
+    // Firewall map, with owner numbers when several operators play
+    pub fn map_lines(&self) -> Vec<String> {
+        render_firewall_map(&self.system.firewall, self.rows, self.cols, self.multi(), false, self.style)
+    }
+
+    // Experience each seat earned: held nodes, opened locks and a share of the win
+    pub fn xp_awards(&self) -> Vec<u32> {
+        let win_bonus: u32 = match self.system.difficulty {
+            Difficulty::Easy => 60,
+            Difficulty::Normal => 100,
+            Difficulty::Hard => 150,
+        };
+        self.seats
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                let nodes = self.system.owned_nodes(s.player.id) as u32;
+                let mut xp = 10 * nodes + 15 * s.locks_open as u32;
+                if self.victors.contains(&i) {
+                    xp += win_bonus;
+                    xp += self
+                        .mission
+                        .bonuses
+                        .iter()
+                        .filter(|b| self.progress(i, b.objective) == Progress::Done)
+                        .map(|b| b.xp)
+                        .sum::<u32>();
+                }
+                xp
+            })
+            .collect()
+    }
+
+    // Nodes held by an operator (by the whole crew in coop)
+    fn crew_nodes(&self, seat: usize) -> usize {
+        match self.mode {
+            PlayMode::Cooperative => self.system.compromised_nodes(),
+            PlayMode::Competitive => self.system.owned_nodes(self.seats[seat].player.id),
+        }
+    }
+
+    // Live state of an objective for a seat (the crew's record counts in coop)
+    pub fn progress(&self, seat: usize, objective: Objective) -> Progress {
+        let coop = self.mode == PlayMode::Cooperative;
+        let s = &self.seats[seat];
+        let id = s.player.id;
+        match objective {
+            Objective::ControlFirewall => {
+                holding(self.crew_nodes(seat), self.system.victory_nodes())
+            }
+            Objective::OpenVault => {
+                let opened = if coop { self.seats.iter().map(|s| s.locks_open).max().unwrap_or(0) } else { s.locks_open };
+                holding(opened, self.system.vault.locks.len())
+            }
+            Objective::DataStores => {
+                let stores = self.mission.active_data_stores();
+                let held = stores
+                    .iter()
+                    .map(|&(r, c)| &self.system.firewall[r * self.cols + c])
+                    .filter(|n| n.state == NodeState::Compromised && (coop || n.owner == Some(id)))
+                    .count();
+                holding(held, stores.len())
+            }
+            Objective::NoExplosions => {
+                let blown: u32 = if coop { self.seats.iter().map(|s| s.explosions).sum() } else { s.explosions };
+                self.kept_clean(blown == 0)
+            }
+            Objective::EnergyAbove(n) => match (self.over, s.player.energy > n) {
+                (true, true) => Progress::Done,
+                (true, false) => Progress::Failed,
+                (false, _) => Progress::Open(tr!("obj.energy_now", s.player.energy)),
+            },
+            Objective::CodeInGuesses(n) => match s.cracked_in {
+                Some(g) if g <= n => Progress::Done,
+                Some(_) => Progress::Failed,
+                None if s.brute_cracked || self.guesses_for(seat) > n => Progress::Failed,
+                None => Progress::Open(tr!("obj.guesses_used", self.guesses_for(seat), n)),
+            },
+            Objective::NoBruteForce => {
+                let used = if coop {
+                    self.seats.iter().any(|s| s.player.brute_force_used > 0)
+                } else {
+                    s.player.brute_force_used > 0
+                };
+                self.kept_clean(!used)
+            }
+        }
+    }
+
+    // "Never do X" objectives only count as met once the heist is over
+    fn kept_clean(&self, clean: bool) -> Progress {
+        match (clean, self.over) {
+            (false, _) => Progress::Failed,
+            (true, true) => Progress::Done,
+            (true, false) => Progress::Open(tr!("obj.so_far")),
+        }
+    }
+
+    // Code guesses that count for a seat (the whole crew's in coop)
+    fn guesses_for(&self, seat: usize) -> u32 {
+        match self.mode {
+            PlayMode::Cooperative => self.seats.iter().map(|s| s.code_guesses).sum(),
+            PlayMode::Competitive => self.seats[seat].code_guesses,
+        }
+    }
+
+    // Points so far: primary and optional objectives met, plus time left for the winners
+    pub fn score(&self, seat: usize) -> u32 {
+        let primary = PRIMARY.iter().filter(|&&o| self.progress(seat, o) == Progress::Done).count() as u32;
+        // Once the heist is over, only the winners keep their optional objectives
+        let counts = !self.over || self.victors.contains(&seat);
+        let bonus: u32 = self
+            .mission
+            .bonuses
+            .iter()
+            .filter(|b| counts && self.progress(seat, b.objective) == Progress::Done)
+            .map(|b| b.xp * objectives::BONUS_POINTS_PER_XP)
+            .sum();
+        let time = if self.victors.contains(&seat) {
+            self.system.lock_timer.max(0) as u32 * objectives::TIMER_POINTS
+        } else {
+            0
+        };
+        primary * objectives::PRIMARY_POINTS + bonus + time
+    }
+
+    // Checklist of every objective with its live state
+    pub fn objective_lines(&self, seat: usize) -> Vec<String> {
+        let stores = &self.mission.data_stores;
+        let mut lines = vec![tr!("heist.objectives", self.mission.codename, self.score(seat))];
+        lines.push(tr!("heist.primary"));
+        for objective in PRIMARY {
+            let p = self.progress(seat, objective);
+            lines.push(format!("  {} {}{}", p.mark(), objective.describe(stores), p.note()));
+        }
+        if !self.mission.bonuses.is_empty() {
+            lines.push(tr!("heist.optional"));
+        }
+        for bonus in &self.mission.bonuses {
+            let p = self.progress(seat, bonus.objective);
+            lines.push(format!("  {} {}{} +{} XP", p.mark(), bonus.objective.describe(stores), p.note(), bonus.xp));
+        }
+        lines
+    }
+
+    // One-line objective summary for the turn status
+    fn objective_summary(&self, seat: usize) -> String {
+        let done = |list: &mut dyn Iterator<Item = Objective>| {
+            list.filter(|&o| self.progress(seat, o) == Progress::Done).count()
+        };
+        let practice = if self.rewound {
+            format!(" ({})", tr!("heist.practice_unrecorded"))
+        } else if self.practice {
+            format!(" ({})", tr!("heist.practice"))
+        } else {
+            String::new()
+        };
+        tr!(
+            "heist.objective_summary",
+            done(&mut PRIMARY.into_iter()),
+            PRIMARY.len(),
+            done(&mut self.mission.bonuses.iter().map(|b| b.objective)),
+            self.mission.bonuses.len(),
+            self.score(seat),
+            practice
+        )
+    }
+
+    // Optional objectives met by the winners and everyone's final score
+    fn debrief_lines(&self) -> Vec<String> {
+        let stores = &self.mission.data_stores;
+        let mut lines = vec![tr!("heist.debrief", self.mission.codename)];
+        for &i in &self.victors {
+            for bonus in &self.mission.bonuses {
+                let met = self.progress(i, bonus.objective) == Progress::Done;
+                lines.push(format!(
+                    "  {}{}: {} — {}",
+                    if self.victors.len() > 1 { format!("{}, ", self.seats[i].player.name) } else { String::new() },
+                    if met { tr!("heist.bonus_done") } else { tr!("heist.bonus_missed") },
+                    bonus.objective.describe(stores),
+                    if met { format!("+{} XP", bonus.xp) } else { tr!("heist.no_bonus") }
+                ));
+            }
+        }
+        for (i, s) in self.seats.iter().enumerate() {
+            lines.push(format!("  {}", tr!("heist.final_score", s.player.name, self.score(i))));
+        }
+        if self.rewound {
+            lines.push(format!("  {}", tr!("heist.practice_debrief")));
+        }
+        lines
+    }
+
+    // Hand out the final result once (None while the heist is running)
+    pub fn take_result(&mut self) -> Option<GameResult> {
+        self.result.take()
+    }
+
+    // Check loss conditions, skip operators who are out and show the turn status
+    pub fn begin_turn(&mut self) -> Outcome {
+        let mut out = Outcome::default();
+
+        loop {
+            if self.result.is_some() {
+                return out;
+            }
+            if self.system.lock_timer <= 0 {
+                self.fail(&mut out, &tr!("heist.detected"));
+                return out;
+            }
+            if self.seats.iter().all(|s| s.out.is_some()) {
+                self.fail(&mut out, &tr!("heist.all_left"));
+                return out;
+            }
+            if self.defending {
+                out.lines.extend(self.defender_status_lines());
+                return out;
+            }
+
+            let solo = self.seats.len() == 1;
+            let seat = &mut self.seats[self.turn];
+            if seat.out.is_some() {
+                self.turn = (self.turn + 1) % self.seats.len();
+                continue;
+            }
+            if seat.player.energy <= 0 {
+                if solo {
+                    self.fail(&mut out, &tr!("heist.no_energy"));
+                    return out;
+                }
+                seat.out = Some(tr!("heist.out_of_energy"));
+                out.say_all(tr!("heist.drops_out", seat.player.name));
+                continue;
+            }
+
+            // The mission script reacts once per turn, then the checks above run again
+            if self.script.is_some() && self.hooked != Some(self.turns_taken) {
+                self.hooked = Some(self.turns_taken);
+                for line in self.hook(self.turn, Hook::TurnStart) {
+                    out.say_all(line);
+                }
+                continue;
+            }
+            break;
+        }
+
+        if self.practice {
+            self.snapshot();
+        }
+        out.lines.extend(self.status_lines());
+        out
+    }
+
+    // Save the state at the start of this turn (once per turn)
+    fn snapshot(&mut self) {
+        if self.history.last().is_some_and(|s| s.taken == self.turns_taken) {
+            return;
+        }
+        self.history.push(Snapshot {
+            taken: self.turns_taken,
+            system: self.system.clone(),
+            seats: self.seats.clone(),
+            turn: self.turn,
+            defender: self.defender.clone(),
+            script: self.script.as_ref().map(|s| s.state()),
+            hooked: self.hooked,
+        });
+        if self.history.len() > UNDO_DEPTH {
+            self.history.remove(0);
+        }
+    }
+
+    // Rewind to the start of the turn `steps` turns back (practice mode only)
+    fn undo(&mut self, steps: usize) -> Outcome {
+        let mut out = Outcome::default();
+        if !self.practice {
+            out.lines.push(tr!("heist.undo_practice_only"));
+            return out;
+        }
+        // The last snapshot is the start of the current turn
+        if self.history.len() < 2 {
+            out.lines.push(tr!("heist.undo_nothing"));
+            return out;
+        }
+        if steps == 0 || steps >= self.history.len() {
+            out.lines.push(tr!("heist.undo_range", self.history.len() - 1));
+            return out;
+        }
+        self.history.truncate(self.history.len() - steps);
+        let Some(snap) = self.history.last().cloned() else {
+            return out;
+        };
+        self.system = snap.system;
+        self.seats = snap.seats;
+        self.turn = snap.turn;
+        self.defender = snap.defender;
+        self.turns_taken = snap.taken;
+        self.hooked = snap.hooked;
+        if let (Some(script), Some(state)) = (&self.script, snap.script) {
+            script.restore(state);
+        }
+        self.defending = false;
+        self.rewound = true;
+        out.say_all(tr!("heist.rewound", steps));
+        out.turn_over = true;
+        out
+    }
+
+    // Whether undo was used (scores and XP are not recorded)
+    pub fn rewound(&self) -> bool {
+        self.rewound
+    }
+
+    // Turn status for the operator whose turn it is
+    fn status_lines(&self) -> Vec<String> {
+        let player = &self.seats[self.turn].player;
+        let mut lines = Vec::new();
+
+        if self.multi() {
+            lines.push(format!("\n{}", tr!("heist.turn_of", player.name, player.id + 1)));
+        } else {
+            lines.push(format!("\n{}", tr!("heist.turn_status")));
+        }
+        lines.push(tr!(
+            "heist.status",
+            self.system.lock_timer,
+            self.system.alert_level,
+            self.detection_tag(),
+            player.energy,
+            EnergyState::of(player.energy, player.rated_energy).tag(),
+            player.hacks_done,
+            player.brute_force_used
+        ));
+        if self.multi() {
+            lines.push(tr!(
+                "heist.firewall_yours",
+                self.system.compromised_nodes(),
+                self.system.firewall.len(),
+                self.system.owned_nodes(player.id)
+            ));
+        } else {
+            lines.push(tr!(
+                "heist.firewall",
+                self.system.compromised_nodes(),
+                self.system.firewall.len()
+            ));
+        }
+        lines.push(tr!("heist.vault", self.lock_progress(self.turn)));
+        lines.push(self.objective_summary(self.turn));
+        lines.push(tr!("heist.actions"));
+        if let Some(target) = self.seats[self.turn].route {
+            lines.push(match self.plan_route(self.turn, target) {
+                Some(plan) if !plan.steps.is_empty() => tr!(
+                    "heist.route_queued",
+                    target.0, target.1, plan.steps[0].0, plan.steps[0].1, plan.turns(), plan.energy
+                ),
+                _ => tr!("heist.route_queued_blocked", target.0, target.1),
+            });
+        }
+        lines.push(tr!("heist.forecast", self.cost_forecast(self.turn).join(", ")));
+        lines
+    }
+
+    // What each action would cost the operator right now
+    fn cost_forecast(&self, seat: usize) -> Vec<String> {
+        let player = &self.seats[seat].player;
+        let mut costs = vec![
+            format!("scan: {}", self.system.scan_cost(player, ScanTarget::Any)),
+            format!("scan code: {}", self.system.scan_cost(player, ScanTarget::Code)),
+            format!("scan grid: {}", self.system.scan_cost(player, ScanTarget::Grid)),
+        ];
+        if let Some(node) = best_target(&self.system.firewall, &|n| self.reachable(seat, n.coord)) {
+            costs.push(format!(
+                "hack ({},{}): {}",
+                node.coord.0,
+                node.coord.1,
+                player.cost(hack_price(player, node.difficulty))
+            ));
+        }
+        if let Some(cost) = self.lock_cost(seat) {
+            costs.push(format!("guess: {}", cost));
+        }
+        if matches!(self.system.vault.locks.get(self.seats[seat].locks_open), Some(Lock::Code)) {
+            costs.push(format!("brute: {}", player.cost(energy::BRUTE_FORCE)));
+        }
+        costs
+    }
+
+    // Energy cost of one attempt at the operator's current lock
+    fn lock_cost(&self, seat: usize) -> Option<i32> {
+        let player = &self.seats[seat].player;
+        let base = match self.system.vault.locks.get(self.seats[seat].locks_open)? {
+            Lock::Code if player.is(OperatorClass::Cracker) => energy::CODE_GUESS - CRACKER_GUESS_DISCOUNT,
+            Lock::Code => energy::CODE_GUESS,
+            Lock::Puzzle(puzzle) => puzzle.cost(),
+        };
+        Some(player.cost(base))
+    }
+
+    // Noise from an operator's action raises the IDS alert, softened by their stealth
+    fn make_noise(&mut self, seat: usize, amount: u32, out: &mut Outcome) {
+        let gain = self.seats[seat].player.skills.alert_gain(amount);
+        self.system.raise_alert(gain);
+        out.lines.push(tr!("heist.noise", gain, self.system.alert_level, self.system.detection().label()));
+    }
+
+    // Remove an operator from the heist (retreat or disconnect)
+    pub fn drop_out(&mut self, seat: usize, reason: &str) -> Outcome {
+        let mut out = Outcome::default();
+        if self.seats[seat].out.is_none() {
+            self.seats[seat].out = Some(reason.to_string());
+            out.say_all(tr!("heist.left", self.seats[seat].player.name, reason));
+            if seat == self.turn {
+                self.turn = (self.turn + 1) % self.seats.len();
+                out.turn_over = true;
+            }
+        }
+        out
+    }
+
+    // Apply one operator's action to the game state
+    pub fn apply(&mut self, seat: usize, action: Action) -> Outcome {
+        let mut out = Outcome::default();
+
+        if self.result.is_some() {
+            out.lines.push(tr!("heist.over"));
+            return out;
+        }
+
+        // Practice rewinds are allowed on the operator's own turn
+        if let Action::Undo(steps) = action {
+            if seat != self.turn || self.defending {
+                out.lines.push(tr!("heist.not_your_turn", self.actor_name()));
+                return out;
+            }
+            return self.undo(steps);
+        }
+
+        // Information actions are allowed at any time and never use a turn
+        if action.is_free() {
+            out.lines = self.info(seat, action);
+            return out;
+        }
+
+        if seat != self.turn || self.defending {
+            out.lines.push(tr!("heist.not_your_turn", self.actor_name()));
+            return out;
+        }
+
+        let name = self.seats[seat].player.name.clone();
+        let kind = action.kind();
+
+        match action {
+            // Retreat / exit
+            Action::Retreat => {
+                if !self.multi() {
+                    self.fail(&mut out, &tr!("heist.retreat_solo"));
+                    out.turn_over = true;
+                    return out;
+                }
+                return self.drop_out(seat, &tr!("heist.retreated"));
+            }
+
+            // Scan for hints
+            Action::Scan(target) => {
+                let Seat { player, notebook, .. } = &mut self.seats[seat];
+                match self.system.scan(player, notebook, target) {
+                    Some(h) => {
+                        out.lines.extend(h.notices);
+                        out.lines.push(h.text);
+                        self.make_noise(seat, noise::ALERT_SCAN, &mut out); // Scans are not silent
+                        if self.mode == PlayMode::Cooperative {
+                            // The crew reads the same IDS log
+                            for s in self.seats.iter_mut() {
+                                self.system.brief_rekeys(&mut s.notebook);
+                            }
+                        }
+                        if let Some(clue) = h.clue {
+                            self.note_clue(seat, clue);
+                        }
+                    }
+                    None => out.lines.push(tr!("heist.scan_failed")),
+                }
+                out.announce.push(tr!("heist.runs_scan", name));
+                self.system.lock_timer -= 1;
+            }
+
+            // Bare "hack" needs a target
+            Action::Hack => {
+                out.lines.push(tr!("heist.hack_usage"));
+                return out;
+            }
+
+            // Hack firewall node
+            Action::HackNode(r, c) => {
+                if r >= self.rows || c >= self.cols {
+                    out.lines.push(tr!("game.out_of_range"));
+                    return out;
+                }
+
+                let idx = r * self.cols + c;
+                let open = matches!(self.system.firewall[idx].state, NodeState::Secure | NodeState::Vulnerable);
+                if open && !self.reachable(seat, (r, c)) {
+                    out.lines.push(tr!("fw.out_of_reach", r, c));
+                    return out; // The turn is not used
+                }
+                let player = &mut self.seats[seat].player;
+                let tries = player.hacks_done;
+                let success = attempt_hack_node(player, &mut self.system.firewall[idx], &mut out.lines);
+                if self.seats[seat].player.hacks_done > tries {
+                    if success {
+                        self.seats[seat].tally.hacks_won += 1;
+                    }
+                    // Clean hacks are quiet, failures louder, explosions loudest
+                    let mut amount = if success { noise::ALERT_HACK } else { noise::ALERT_FAILED_HACK };
+                    if self.system.firewall[idx].state == NodeState::Exploded {
+                        self.seats[seat].explosions += 1;
+                        amount += noise::ALERT_EXPLOSION;
+                    }
+                    self.make_noise(seat, amount, &mut out);
+                    let node = node_map(&self.system.firewall[idx]);
+                    let lines = self.hook(seat, Hook::Hack(node, success));
+                    out.lines.extend(lines);
+                }
+                if success {
+                    let reveal_roll = rand::thread_rng().gen_range(0..100);
+                    if reveal_roll < 20 {
+                        let pos = rand::thread_rng().gen_range(0..4);
+                        out.lines.push(tr!("heist.leak", pos + 1, self.system.code_digits[pos]));
+                        self.note_clue(seat, CodeClue::DigitAt(pos, self.system.code_digits[pos]));
+                    }
+                }
+                let verdict = if success { tr!("heist.hack_won") } else { tr!("heist.hack_lost") };
+                out.announce.push(tr!("heist.hacks_node", name, r, c, verdict));
+
+                self.system.lock_timer -= 1;
+            }
+
+            // Attempt the current vault lock
+            Action::Guess(input) => {
+                let layer = self.seats[seat].locks_open;
+                let Some(lock) = self.system.vault.locks.get(layer).cloned() else {
+                    out.lines.push(tr!("heist.all_open"));
+                    return out;
+                };
+                let shown = out.lines.len();
+                let Some(opened) = self.try_lock(seat, &lock, &input, &mut out) else {
+                    return out; // Input did not fit the lock; the turn is not used
+                };
+                if self.script.is_some() {
+                    let feedback = self.guess_feedback(seat, &lock, opened, &out.lines[shown..]);
+                    let lines = self.hook(seat, Hook::Guess(input, feedback));
+                    out.lines.extend(lines);
+                }
+                if opened {
+                    self.open_lock(seat, &mut out);
+                } else {
+                    self.make_noise(seat, noise::ALERT_FAILED_GUESS, &mut out);
+                }
+                self.system.lock_timer -= 1;
+            }
+
+            // Attempt brute force
+            Action::BruteForce => {
+                let layer = self.seats[seat].locks_open;
+                if !matches!(self.system.vault.locks.get(layer), Some(Lock::Code)) {
+                    out.lines.push(tr!("heist.brute_code_only"));
+                    return out;
+                }
+                let tries = self.seats[seat].player.brute_force_used;
+                let success = self.system.brute_force(&mut self.seats[seat].player, &mut out.lines);
+                if self.seats[seat].player.brute_force_used > tries {
+                    self.make_noise(seat, noise::ALERT_BRUTE_FORCE, &mut out);
+                }
+                if success {
+                    out.announce.push(tr!("heist.brute_won", name));
+                    self.seats[seat].brute_cracked = true;
+                    self.seats[seat].tally.brutes_won += 1;
+                    self.open_lock(seat, &mut out);
+                } else {
+                    out.announce.push(tr!("heist.brute_try", name));
+                }
+                self.system.lock_timer -= 2;
+            }
+
+            // Go quiet so the IDS settles
+            Action::LieLow => {
+                self.system.alert_level = self.system.alert_level.saturating_sub(noise::ALERT_LIE_LOW);
+                out.lines.push(tr!("heist.lie_low", self.system.alert_level, self.system.detection().label()));
+                out.announce.push(tr!("heist.lies_low", name));
+                self.system.lock_timer -= 1;
+            }
+
+            // Queue a route and hack its first node
+            Action::Route(r, c, _) => {
+                let lines = self.route_lines(seat, (r, c), true);
+                if self.plan_route(seat, (r, c)).is_none_or(|plan| plan.steps.is_empty()) {
+                    out.lines = lines;
+                    return out; // Nothing to queue; the turn is not used
+                }
+                self.seats[seat].route = Some((r, c));
+                let mut out = self.follow_route(seat);
+                out.lines.splice(0..0, lines);
+                return out;
+            }
+
+            // Hack the next node on the queued route
+            Action::FollowRoute => return self.follow_route(seat),
+
+            // Free actions and undo were handled above
+            Action::Status | Action::Analyze | Action::Help | Action::Probe(_) | Action::Undo(_) => {}
+        };
+
+        self.seats[seat].tally.note(kind);
+        self.end_turn(seat, &mut out);
+        self.check_achievements(&mut out);
+        out
+    }
+
+    // One operator's run, for lifetime stats
+    pub fn run_record(&self, seat: usize) -> Run {
+        let s = &self.seats[seat];
+        Run {
+            difficulty: self.system.difficulty,
+            won: self.victors.contains(&seat),
+            hacks: s.player.hacks_done,
+            brutes: s.player.brute_force_used as u32,
+            explosions: s.explosions,
+            tally: s.tally.clone(),
+        }
+    }
+
+    // Whether an operator has met an achievement's condition
+    fn achieved(&self, seat: usize, achievement: Achievement) -> bool {
+        let s = &self.seats[seat];
+        let won = self.victors.contains(&seat);
+        match achievement {
+            Achievement::Ghost => won && s.explosions == 0,
+            Achievement::BruteForcer => won && s.brute_cracked,
+            Achievement::Mastermind => !s.brute_cracked && s.cracked_in.is_some_and(|n| n <= MASTERMIND_GUESSES),
+            Achievement::FullSweep => self.crew_nodes(seat) == self.system.firewall.len(),
+        }
+    }
+
+    // Unlock and announce newly met achievements (not in rewound practice runs)
+    fn check_achievements(&mut self, out: &mut Outcome) {
+        if self.rewound {
+            return;
+        }
+        for seat in 0..self.seats.len() {
+            for a in ACHIEVEMENTS {
+                if self.seats[seat].achievements.contains(&a) || !self.achieved(seat, a) {
+                    continue;
+                }
+                self.seats[seat].achievements.push(a);
+                out.say_all(tr!("heist.achievement", self.seats[seat].player.name, a.name(), a.describe()));
+            }
+        }
+    }
+
+    // One attempt at a lock; Some(true) if it opened, None if the input did not fit
+    fn try_lock(&mut self, seat: usize, lock: &Lock, input: &str, out: &mut Outcome) -> Option<bool> {
+        let name = self.seats[seat].player.name.clone();
+        let cost = self.lock_cost(seat).unwrap_or(0);
+        if self.seats[seat].player.energy < cost {
+            out.lines.push(tr!("heist.lock_no_energy", cost));
+            return None;
+        }
+        match lock {
+            Lock::Code => {
+                let Some(guess) = parse_code(input) else {
+                    out.lines.push(tr!("heist.code_format"));
+                    return None;
+                };
+                let (cp, cw) = mastermind_feedback(&self.system.code_digits, &guess);
+                out.lines.push(tr!("heist.feedback", cp, cw));
+                self.note_guess(seat, guess, (cp, cw));
+                self.seats[seat].code_guesses += 1;
+                self.seats[seat].player.energy -= cost;
+                if cp == 4 {
+                    out.lines.push(tr!("heist.cracked"));
+                    out.announce.push(tr!("heist.cracked_by", name));
+                    return Some(true);
+                }
+                out.lines.push(tr!("heist.guess_failed"));
+                out.announce.push(tr!("heist.tries_code", name));
+                Some(false)
+            }
+            Lock::Puzzle(puzzle) => {
+                let attempt = match puzzle.attempt(input, &self.puzzle_context(seat)) {
+                    Ok(attempt) => attempt,
+                    Err(msg) => {
+                        out.lines.push(msg);
+                        return None;
+                    }
+                };
+                self.seats[seat].player.energy -= cost;
+                out.lines.extend(attempt.lines);
+                if attempt.solved {
+                    out.announce.push(tr!("heist.opened_puzzle", name, puzzle.label()));
+                } else {
+                    out.announce.push(tr!("heist.works_puzzle", name, puzzle.label()));
+                }
+                Some(attempt.solved)
+            }
+        }
+    }
+
+    // A lock's answer to a guess, for the on_guess hook
+    fn guess_feedback(&self, seat: usize, lock: &Lock, opened: bool, lines: &[String]) -> rhai::Map {
+        let mut m = rhai::Map::new();
+        let (name, correct, misplaced) = match lock {
+            Lock::Code => {
+                let last = self.seats[seat].notebook.guesses.last();
+                ("code".to_string(), last.map_or(0, |g| g.correct), last.map_or(0, |g| g.misplaced))
+            }
+            Lock::Puzzle(puzzle) => (puzzle.name().to_string(), 0, 0),
+        };
+        m.insert("lock".into(), name.into());
+        m.insert("opened".into(), opened.into());
+        m.insert("correct".into(), (correct as i64).into());
+        m.insert("misplaced".into(), (misplaced as i64).into());
+        m.insert("text".into(), lines.join("\n").into());
+        m
+    }
+
+    // Game state a vault puzzle may read for this operator
+    fn puzzle_context(&self, seat: usize) -> PuzzleContext<'_> {
+        PuzzleContext {
+            firewall: &self.system.firewall,
+            rows: self.rows,
+            cols: self.cols,
+            owner: self.seats[seat].player.id,
+            clock: self.system.clock,
+        }
+    }
+
+    // Open the operator's current lock (the crew's, in cooperative play) and reveal the next
+    fn open_lock(&mut self, seat: usize, out: &mut Outcome) {
+        let layer = self.seats[seat].locks_open;
+        let cracked = self.system.vault.code_layer() == Some(layer);
+        let guesses = self.guesses_for(seat);
+        let brute = self.seats[seat].brute_cracked;
+        for (i, s) in self.seats.iter_mut().enumerate() {
+            if i == seat || self.mode == PlayMode::Cooperative {
+                s.locks_open = s.locks_open.max(layer + 1);
+                // Only a guess that opens the code counts towards "cracked in N guesses"
+                if cracked && brute {
+                    s.brute_cracked = true;
+                } else if cracked && s.cracked_in.is_none() {
+                    s.cracked_in = Some(guesses);
+                }
+            }
+        }
+
+        let total = self.system.vault.locks.len();
+        match self.system.vault.locks.get(layer + 1).cloned() {
+            Some(next) => {
+                out.lines.push(tr!("heist.lock_open", layer + 1, total, next.describe()));
+                if let Lock::Puzzle(puzzle) = &next {
+                    out.lines.extend(puzzle.reveal_lines(&self.puzzle_context(seat)));
+                }
+                if total > 1 {
+                    out.announce.push(tr!("heist.opened_lock", self.seats[seat].player.name, layer + 1, total));
+                }
+            }
+            None if total > 1 => out.lines.push(tr!("heist.last_lock")),
+            None => {}
+        }
+    }
+
+    // Output of an information action for one operator
+    fn info(&self, seat: usize, action: Action) -> Vec<String> {
+        let Seat { player, notebook, .. } = &self.seats[seat];
+        match action {
+            // Show player and system status
+            Action::Status => {
+                let mut lines = vec![
+                    tr!("heist.player", format!("{:?}", player)),
+                    tr!(
+                        "heist.system",
+                        self.system.difficulty.label(),
+                        self.system.lock_timer,
+                        self.system.honeypot_active
+                    ),
+                ];
+                lines.extend(self.map_lines());
+                lines.extend(self.lock_lines(seat));
+                lines.extend(self.objective_lines(seat));
+                lines.extend(notebook.history_lines());
+                lines
+            }
+
+            // Count codes consistent with notes
+            Action::Analyze => notebook.analyze(),
+
+            // Preview a route without committing to it
+            Action::Route(r, c, _) => self.route_lines(seat, (r, c), false),
+
+            // Hack odds before committing: one node, or the whole grid
+            Action::Probe(Some((r, c))) if r >= self.rows || c >= self.cols => vec![tr!("game.out_of_range")],
+            Action::Probe(Some((r, c))) => {
+                probe_lines(player, &self.system.firewall[r * self.cols + c], self.reachable(seat, (r, c)))
+            }
+            Action::Probe(None) => self.odds_lines(seat),
+
+            // Show help
+            _ => help_lines(),
+        }
+    }
+
+    // Odds overlay of the grid for one operator
+    pub fn odds_lines(&self, seat: usize) -> Vec<String> {
+        let reach = |n: &FirewallNode| self.reachable(seat, n.coord);
+        let mut lines = render_odds_map(&self.system.firewall, self.rows, self.cols, &self.seats[seat].player, &reach, self.style);
+        if self.edge_reach {
+            lines.push(tr!("fw.reach_note"));
+        }
+        lines
+    }
+
+    // Nodes that give an operator a foothold: their own, or the whole crew's in cooperative play
+    fn holds(&self, seat: usize) -> impl Fn(&FirewallNode) -> bool + use<> {
+        let id = self.seats[seat].player.id;
+        let crew = self.mode == PlayMode::Cooperative;
+        move |n: &FirewallNode| n.state == NodeState::Compromised && (crew || n.owner == Some(id))
+    }
+
+    // Whether an operator can hack a node: any node, or under the edge rule one on the edge or next to a node they hold
+    fn reachable(&self, seat: usize, coord: (usize, usize)) -> bool {
+        !self.edge_reach || in_reach(&self.system.firewall, self.rows, self.cols, coord, &self.holds(seat))
+    }
+
+    // Cheapest route for an operator to a node, starting from the nodes they (or the crew) hold
+    fn plan_route(&self, seat: usize, target: (usize, usize)) -> Option<Route> {
+        if target.0 >= self.rows || target.1 >= self.cols {
+            return None;
+        }
+        route::plan(&self.system.firewall, self.rows, self.cols, &self.seats[seat].player, &self.holds(seat), target)
+    }
+
+    // Route plan with its projected energy and turn cost
+    fn route_lines(&self, seat: usize, target: (usize, usize), queued: bool) -> Vec<String> {
+        let (r, c) = target;
+        if r >= self.rows || c >= self.cols {
+            return vec![tr!("game.out_of_range")];
+        }
+        let Some(plan) = self.plan_route(seat, target) else {
+            return vec![tr!("heist.route_none", r, c)];
+        };
+        if plan.steps.is_empty() {
+            return vec![tr!("heist.route_held", r, c)];
+        }
+        let energy = self.seats[seat].player.energy;
+        let hops: Vec<String> = plan.steps.iter().map(|(r, c)| format!("({},{})", r, c)).collect();
+        let mut lines = vec![
+            tr!("heist.route_plan", r, c, hops.join(" -> ")),
+            tr!(
+                "heist.route_projected",
+                plan.steps.len(),
+                plan.turns(),
+                plan.energy,
+                energy,
+                energy - plan.energy,
+                self.system.lock_timer,
+                self.system.lock_timer - plan.turns() as i32
+            ),
+        ];
+        if plan.energy > energy {
+            lines.push(tr!("heist.route_unaffordable"));
+        }
+        if !queued {
+            lines.push(tr!("heist.route_hint", r, c));
+        }
+        lines
+    }
+
+    // Hack the next node on an operator's queued route, planned afresh from the current board
+    fn follow_route(&mut self, seat: usize) -> Outcome {
+        let mut out = Outcome::default();
+        let Some(target) = self.seats[seat].route else {
+            out.lines.push(tr!("heist.route_empty"));
+            return out;
+        };
+        let Some(&(r, c)) = self.plan_route(seat, target).as_ref().and_then(|plan| plan.steps.first()) else {
+            self.seats[seat].route = None;
+            out.lines.push(match self.plan_route(seat, target) {
+                Some(_) => tr!("heist.route_complete", target.0, target.1),
+                None => tr!("heist.route_blocked", target.0, target.1),
+            });
+            return out;
+        };
+        let player = &self.seats[seat].player;
+        let cost = player.cost(hack_price(player, self.system.firewall[r * self.cols + c].difficulty));
+        if player.energy < cost {
+            out.lines.push(tr!("heist.route_no_energy", r, c, cost));
+            return out;
+        }
+
+        let mut out = self.apply(seat, Action::HackNode(r, c));
+        out.lines.insert(0, tr!("heist.route_step", target.0, target.1, r, c));
+        match self.plan_route(seat, target) {
+            Some(plan) if !plan.steps.is_empty() => {} // Still on the way
+            Some(_) => {
+                self.seats[seat].route = None;
+                out.lines.push(tr!("heist.route_complete", target.0, target.1));
+            }
+            None => {
+                self.seats[seat].route = None;
+                out.lines.push(tr!("heist.route_blocked", target.0, target.1));
+            }
+        }
+        out
+    }
+
+    // Record a clue for the operator (the whole crew in cooperative play)
+    fn note_clue(&mut self, seat: usize, clue: CodeClue) {
+        let epoch = self.system.code_epoch();
+        for (i, s) in self.seats.iter_mut().enumerate() {
+            if i == seat || self.mode == PlayMode::Cooperative {
+                s.notebook.record_clue(clue, epoch);
+            }
+        }
+    }
+
+    // Record a guess for the operator (the whole crew in cooperative play)
+    fn note_guess(&mut self, seat: usize, guess: [u8; 4], feedback: (usize, usize)) {
+        let epoch = self.system.code_epoch();
+        for (i, s) in self.seats.iter_mut().enumerate() {
+            if i == seat || self.mode == PlayMode::Cooperative {
+                s.notebook.record_guess(guess, feedback, epoch);
+            }
+        }
+    }
+
+    // Victory check, penalties, regeneration and passing the turn
+    fn end_turn(&mut self, seat: usize, out: &mut Outcome) {
+        // Check victory condition
+        let victory = PRIMARY.iter().all(|&o| self.progress(seat, o) == Progress::Done);
+        if victory {
+            let elapsed = self.start_time.elapsed();
+            if self.multi() {
+                match self.mode {
+                    PlayMode::Competitive => out.say_all(tr!("heist.wins", self.seats[seat].player.name)),
+                    PlayMode::Cooperative => out.say_all(tr!("heist.crew_wins")),
+                }
+            }
+            out.say_all(tr!("heist.success", format!("{:.2?}", elapsed)));
+            self.victors = match self.mode {
+                PlayMode::Competitive => vec![seat],
+                PlayMode::Cooperative => (0..self.seats.len()).filter(|&i| self.seats[i].out.is_none()).collect(),
+            };
+            self.result = Some(GameResult::Success);
+            self.over = true;
+            for line in self.debrief_lines() {
+                out.say_all(line);
+            }
+            out.turn_over = true;
+            return;
+        }
+
+        // Apply penalties (everyone sees) and energy rules (private)
+        let mut log = Vec::new();
+        self.system.apply_explosion_penalty(&mut log);
+        for line in log {
+            out.say_all(line);
+        }
+        let owned = self.system.owned_nodes(self.seats[seat].player.id);
+        energy::end_of_turn(&mut self.seats[seat].player, self.system.difficulty, owned, &mut out.lines);
+
+        // A nervous IDS drains the timer and sets off security events
+        for line in self.system.detection_sweep(&mut self.seats[seat].player) {
+            out.say_all(line);
+        }
+
+        // The vault may re-key quietly; only a scan reveals it
+        if self.system.tick_rotation().is_some() {
+            self.reset_cracked();
+        }
+
+        // Pass the turn to the next operator; a full round ticks the clock
+        self.turns_taken += 1;
+        self.turn = (self.turn + 1) % self.seats.len();
+        if self.turn == 0 {
+            self.system.clock += 1;
+        }
+        out.turn_over = true;
+
+        // The sysadmin answers every attacker turn
+        let difficulty = self.system.difficulty;
+        if let Some(d) = self.defender.as_mut() {
+            d.collect_income(difficulty);
+            match d.kind {
+                DefenderKind::Human => self.defending = true,
+                DefenderKind::Ai => self.run_ai_defense(out),
+            }
+        }
+    }
+
+    // Sysadmin view: everything the attackers cannot see
+    fn defender_status_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        let Some(d) = &self.defender else { return lines };
+
+        lines.push(format!("\n{}", tr!("heist.sysadmin_turn", d.name)));
+        lines.push(tr!(
+            "heist.defense_status",
+            d.budget,
+            self.system.lock_timer,
+            self.system.alert_level,
+            self.detection_tag(),
+            crate::notebook::format_code(&self.system.code_digits)
+        ));
+        lines.extend(render_firewall_map(&self.system.firewall, self.rows, self.cols, true, true, self.style));
+        lines.extend(render_difficulty_map(&self.system.firewall, self.rows, self.cols));
+        for seat in &self.seats {
+            let code = if self.system.vault.code_open(seat.locks_open) { tr!("heist.code_cracked") } else { tr!("heist.code_locked") };
+            let out = if seat.out.is_some() { format!(" [{}]", tr!("heist.out_tag")) } else { String::new() };
+            lines.push(format!(
+                "  {}",
+                tr!(
+                    "heist.defense_seat",
+                    seat.player.name,
+                    seat.player.id + 1,
+                    seat.player.energy,
+                    self.system.owned_nodes(seat.player.id),
+                    code,
+                    seat.locks_open,
+                    self.system.vault.locks.len(),
+                    out
+                )
+            ));
+        }
+        lines.push(tr!(
+            "heist.moves",
+            defense::SECURE_COST,
+            defense::TRAP_COST,
+            defense::HARDEN_COST,
+            defense::ROTATE_COST
+        ));
+        lines
+    }
+
+    // Apply one sysadmin move
+    pub fn defend(&mut self, mv: DefenseMove) -> Outcome {
+        let mut out = Outcome::default();
+        if !self.defending || self.result.is_some() {
+            out.lines.push(tr!("heist.wait_attackers"));
+            return out;
+        }
+
+        if mv != DefenseMove::End {
+            let budget = self.defender.as_ref().map_or(0, |d| d.budget);
+            match defense::apply_move(&mut self.system, mv, budget, self.cols) {
+                Ok(cost) => {
+                    if let Some(d) = self.defender.as_mut() {
+                        d.budget -= cost;
+                    }
+                    self.report_defense(mv, true, &mut out);
+                }
+                Err(msg) => {
+                    out.lines.push(msg);
+                    return out;
+                }
+            }
+        }
+
+        // Turn ends on request or when nothing is left to spend
+        let budget = self.defender.as_ref().map_or(0, |d| d.budget);
+        if mv == DefenseMove::End || budget == 0 {
+            self.defending = false;
+            out.turn_over = true;
+            out.say_all(tr!("heist.sysadmin_done"));
+        } else {
+            out.lines.push(tr!("heist.budget_left", budget));
+        }
+        out
+    }
+
+    // Hand a human sysadmin's role to the AI (e.g. after a disconnect)
+    pub fn automate_defender(&mut self) -> Outcome {
+        let mut out = Outcome::default();
+        if let Some(d) = self.defender.as_mut() {
+            d.kind = DefenderKind::Ai;
+            out.say_all(tr!("heist.automated", d.name));
+        }
+        if self.defending {
+            self.defending = false;
+            self.run_ai_defense(&mut out);
+            out.turn_over = true;
+        }
+        out
+    }
+
+    // Let the automated sysadmin make its move
+    fn run_ai_defense(&mut self, out: &mut Outcome) {
+        let budget = self.defender.as_ref().map_or(0, |d| d.budget);
+        let cracked = self.seats.iter().any(|s| self.system.vault.code_open(s.locks_open));
+        let owners: Vec<usize> = self
+            .seats
+            .iter()
+            .filter(|s| s.out.is_none())
+            .map(|s| s.player.id)
+            .collect();
+
+        if let Some(mv) = defense::ai_move(&self.system, budget, cracked, &owners)
+            && let Ok(cost) = defense::apply_move(&mut self.system, mv, budget, self.cols)
+        {
+            if let Some(d) = self.defender.as_mut() {
+                d.budget -= cost;
+            }
+            self.report_defense(mv, false, out); // `out` belongs to an attacker: no private details
+        }
+    }
+
+    // Announce visible changes to everyone; `to_sysadmin` adds the secret details for a human sysadmin
+    fn report_defense(&mut self, mv: DefenseMove, to_sysadmin: bool, out: &mut Outcome) {
+        let detail = match mv {
+            DefenseMove::Secure(r, c) => {
+                out.say_all(tr!("heist.resecured", r, c));
+                None
+            }
+            DefenseMove::Trap(r, c) => Some(tr!("heist.trapped", r, c)),
+            DefenseMove::Harden(r, c) => {
+                let diff = self.system.firewall[r * self.cols + c].difficulty;
+                Some(tr!("heist.hardened", r, c, diff))
+            }
+            DefenseMove::Rotate(pos) => {
+                self.reset_cracked();
+                Some(tr!("heist.rekeyed_digit", pos + 1, self.system.code_digits[pos]))
+            }
+            DefenseMove::End => None,
+        };
+        // An AI sysadmin's outcome goes to the attacker whose turn ended, so its secrets stay hidden
+        if to_sysadmin && let Some(line) = detail {
+            out.lines.push(line);
+        }
+    }
+
+    // A re-keyed code closes its lock and every lock behind it
+    fn reset_cracked(&mut self) {
+        if let Some(layer) = self.system.vault.code_layer() {
+            for seat in self.seats.iter_mut().filter(|s| s.locks_open > layer) {
+                seat.locks_open = layer;
+                seat.cracked_in = None; // The new code has to be cracked all over again
+                seat.brute_cracked = false;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Solo heist whose vault is a single code lock
+    fn code_heist() -> Heist {
+        let mut heist = Heist::new(&["ana".to_string()], Difficulty::Easy, PlayMode::Competitive, None);
+        heist.apply_rules(&RuleOptions {
+            vault: Some(vec![LockKind::Code]),
+            ..RuleOptions::default()
+        });
+        heist
+    }
+
+    #[test]
+    fn brute_force_never_counts_as_cracked_in_guesses() {
+        let mut heist = code_heist();
+        heist.seats[0].brute_cracked = true;
+        heist.open_lock(0, &mut Outcome::default());
+        assert_eq!(heist.seats[0].cracked_in, None);
+        assert_eq!(heist.progress(0, Objective::CodeInGuesses(3)), Progress::Failed);
+        assert!(!heist.achieved(0, Achievement::Mastermind));
+    }
+
+    #[test]
+    fn guessed_code_counts_until_rekeyed() {
+        let mut heist = code_heist();
+        heist.seats[0].code_guesses = 2;
+        heist.open_lock(0, &mut Outcome::default());
+        assert_eq!(heist.seats[0].cracked_in, Some(2));
+        assert_eq!(heist.progress(0, Objective::CodeInGuesses(3)), Progress::Done);
+
+        heist.reset_cracked();
+        assert_eq!(heist.seats[0].locks_open, 0);
+        assert_eq!(heist.seats[0].cracked_in, None);
+        heist.seats[0].code_guesses = 4;
+        assert_eq!(heist.progress(0, Objective::CodeInGuesses(3)), Progress::Failed);
+    }
+
+    #[test]
+    fn practice_keeps_undo_depth_snapshots() {
+        let mut heist = code_heist();
+        for taken in 0..UNDO_DEPTH as u32 + 5 {
+            heist.turns_taken = taken;
+            heist.snapshot();
+        }
+        assert_eq!(heist.history.len(), UNDO_DEPTH);
+        assert_eq!(heist.history.last().map(|s| s.taken), Some(UNDO_DEPTH as u32 + 4));
+    }
+}
@@ -1,237 +1,345 @@
 /*
 DATA TYPES USED:
 
-1. GameResult       -> Enum: Success, Failure(String)
-2. name             -> String, player name
+1. GameResult       -> Enum: Success, Failure(String) (from engine)
+2. names            -> Vec<String>, operator handles
 3. difficulty       -> Difficulty enum: Easy, Normal, Hard
-4. player           -> Player struct
-5. rows, cols       -> usize, size of the firewall grid
-6. system           -> SecuritySystem struct
-7. last_scan        -> Option<String>, last scan hint
-8. code_cracked     -> bool, whether code has been guessed
-9. start_time       -> Instant, game start time
-10. input           -> String, player input
-11. action          -> Action enum, parsed player action
-12. choice          -> String, hack target choice
-13. coords          -> String, row and column input for hack
-14. parts           -> Vec<&str>, split coordinates
-15. r, c            -> usize, row and column indices
-16. idx             -> usize, index in firewall vector
-17. success         -> bool, result of hack or brute force
-18. reveal_roll, pos -> u32 / usize, random numbers for hints
-19. elapsed         -> Duration, time elapsed
+4. mode             -> PlayMode enum: Competitive, Cooperative
+5. count            -> usize, number of operators at the keyboard
+6. heist            -> Heist struct, the game engine
+7. outcome          -> Outcome struct, text produced by the engine
+8. input            -> String, player input
+9. action           -> Action enum, parsed player action
+10. choice          -> String, hack target choice
+11. coords          -> String, row and column input for hack
+12. parts           -> Vec<&str>, split coordinates
+13. r, c            -> usize, row and column indices
+14. defender        -> Option<Defender>, the sysadmin role if chosen
+15. mv              -> DefenseMove enum, parsed sysadmin move
+16. rules           -> RuleOptions struct, rule overrides from the command line
+17. attempt         -> String, input for the current vault lock
+18. profiles        -> Vec<Profile>, saved operator profiles, one per seat
+19. xp              -> Vec<u32>, experience each operator earned this mission
+20. classes         -> Vec<Option<OperatorClass>>, archetype picked by each operator
+21. daily           -> Option<&Daily>, the day's challenge when playing the daily mode
+22. results         -> DailyResults struct, saved daily attempts, best scores and wins
+23. run             -> Run struct, one operator's run for lifetime stats
+24. script          -> Option<MissionScript>, Rhai mission hooks from --script
 */
 
 use std::io::{self, Write}; // For input/output and flushing stdout
-use std::time::Instant;      // For timing the game
+use std::path::Path;        // Mission script location
 
 use crate::actions::Action; // Enum for player actions
-use crate::player::Player;  // Player struct
-use crate::security::{SecuritySystem, Difficulty}; // Security system and difficulty
-use crate::security::firewall::{display_firewall_map, attempt_hack_node}; // Firewall functions
-use crate::security::code::{mastermind_feedback, read_code_guess}; // Code functions
-use crate::ui::{display_header, show_help}; // UI helper functions
-use crate::utils::{read_input}; // Utility functions
-use rand::Rng; // Random number generator
-
-// Enum for game outcome
-pub enum GameResult {
-    Success,                // Player wins
-    Failure(String),        // Player loses with message
+use crate::daily::{Daily, DailyResults}; // Daily challenge
+use crate::i18n::tr; // Translated text
+use crate::engine::{GameResult, Heist, PlayMode, RuleOptions, MAX_OPERATORS}; // Game engine
+use crate::player::{OperatorClass, CLASSES}; // Operator archetypes
+use crate::profile::{Profile, Skill}; // Operator profiles and skills
+use crate::script::MissionScript; // Mission script hooks
+use crate::security::Difficulty; // Difficulty enum
+use crate::stats::Stats; // Lifetime stats
+use crate::theme::Style; // Terminal colors
+use crate::security::defense::{Defender, DefenderKind, DefenseMove}; // Sysadmin role
+use crate::ui::{display_header, print_lines}; // UI helper functions
+use crate::utils::{clamp_generic, read_input}; // Utility functions
+
+// Main game loop (terminal, hot-seat)
+pub fn run_game(rules: RuleOptions) -> GameResult {
+    display_header(); // Display game header
+    let style = Style::for_terminal(rules.theme, rules.screen_reader);
+    run_heist(rules, style, None)
 }
 
-// Main game loop
-pub fn run_game() -> GameResult {
-    display_header(); // Display game header
+// Daily challenge: today's fixed heist, with the result saved
+pub fn run_daily(style: Style) -> GameResult {
+    display_header();
+    let daily = Daily::today();
+    print_lines(&daily.header_lines());
+    match DailyResults::load() {
+        Ok(results) => print_lines(&results.summary_lines(&daily)),
+        Err(e) => println!("{}", tr!("game.read_daily", e)),
+    }
+    run_heist(daily.rules(), style, Some(&daily))
+}
+
+// Set up and play one heist; `daily` fixes the difficulty and board
+fn run_heist(rules: RuleOptions, style: Style, daily: Option<&Daily>) -> GameResult {
+    // A broken mission script stops the game before any prompts
+    let script = match rules.script.as_deref().map(|p| MissionScript::load(Path::new(p))).transpose() {
+        Ok(script) => script,
+        Err(e) => return GameResult::Failure(e),
+    };
+
+    // Choose number of operators (hot-seat)
+    println!("{}", tr!("game.operators", MAX_OPERATORS));
+    let count = match read_input() {
+        Ok(s) => s.trim().parse::<usize>().unwrap_or(1),
+        Err(_) => 1,
+    };
+    let count = clamp_generic(count, 1, MAX_OPERATORS);
+
+    // Get player names, profiles and classes
+    let mut names = Vec::with_capacity(count);
+    let mut profiles: Vec<Profile> = Vec::with_capacity(count);
+    let mut classes = Vec::with_capacity(count);
+    for i in 0..count {
+        if count == 1 {
+            println!("{}", tr!("game.handle"));
+        } else {
+            println!("{}", tr!("game.handle_n", i + 1));
+        }
+        let name = match read_input() {
+            Ok(s) if !s.is_empty() => s,
+            _ if count == 1 => "Operator".to_string(), // Default name
+            _ => format!("Operator{}", i + 1),
+        };
+        // Load the operator's profile and offer to spend skill points
+        let mut profile = load_profile(&name);
+        train_prompt(&mut profile);
+        profiles.push(profile);
+
+        names.push(name);
+        classes.push(prompt_class());
+    }
 
-    // Get player name
-    println!("Enter your handle (player name): ");
-    let name = match read_input() {
-        Ok(s) if !s.is_empty() => s,
-        _ => "Operator".to_string(), // Default name
+    // Choose how several operators play together
+    let mode = if count > 1 {
+        println!("{}", tr!("game.mode"));
+        read_input()
+            .ok()
+            .and_then(|s| s.parse::<PlayMode>().ok())
+            .unwrap_or(PlayMode::Competitive)
+    } else {
+        PlayMode::Competitive
     };
 
-    // Select difficulty
-    println!("Choose difficulty: easy / normal / hard (e/n/h). Default: normal");
-    let difficulty = match read_input() {
-        Ok(s) => match s.trim().to_lowercase().as_str() {
-            "e" | "easy" => Difficulty::Easy,
-            "h" | "hard" => Difficulty::Hard,
-            _ => Difficulty::Normal,
-        },
-        Err(_) => Difficulty::Normal,
+    // Select difficulty (the daily challenge sets its own)
+    let difficulty = match daily {
+        Some(d) => d.difficulty,
+        None => {
+            println!("{}", tr!("game.difficulty"));
+            read_input()
+                .ok()
+                .and_then(|s| s.parse::<Difficulty>().ok())
+                .unwrap_or(Difficulty::Normal)
+        }
     };
 
-    // Initialize player
-    let mut player = Player::new(&name, difficulty);
+    // Choose whether a sysadmin defends the vault
+    println!("{}", tr!("game.sysadmin"));
+    let defender = match read_input().unwrap_or_default().trim().to_lowercase().as_str() {
+        "a" | "ai" => Some(Defender::new("ICE", DefenderKind::Ai)),
+        "h" | "human" => {
+            println!("{}", tr!("game.sysadmin_handle"));
+            let name = match read_input() {
+                Ok(s) if !s.is_empty() => s,
+                _ => "Sysadmin".to_string(),
+            };
+            Some(Defender::new(&name, DefenderKind::Human))
+        }
+        _ => None,
+    };
 
-    // Set firewall grid size
-    let rows = 5usize;
-    let cols = 5usize;
+    // Initialize the engine
+    let mut heist = Heist::new(&names, difficulty, mode, defender);
+    heist.apply_rules(&rules);
+    heist.style = style;
+    if let Some(script) = script {
+        heist.set_script(script);
+    }
+    if let Some(d) = daily {
+        let (code, firewall) = d.board(heist.rows, heist.cols);
+        heist.system.vault = d.vault(heist.rows, heist.cols);
+        heist.system.rekey_rng = d.rekey_rng();
+        heist.stage(code, firewall);
+    }
+    for ((seat, profile), class) in heist.seats.iter_mut().zip(&profiles).zip(classes) {
+        seat.player.skills = profile.skills;
+        seat.achievements = profile.achievements.clone();
+        if let Some(class) = class {
+            seat.player.set_class(class);
+        }
+    }
+    print_lines(&heist.intro_lines());
 
-    // Initialize security system
-    let mut system = SecuritySystem::new(difficulty, rows, cols);
+    let result = play(&mut heist);
+    if let Some(d) = daily {
+        record_daily(d, &heist, &result);
+    }
 
-    println!();
-    println!("Welcome, {}. You have {} energy.", player.name, player.energy);
-    println!("Your objective: Compromise the firewall and retrieve the 4-digit vault code before the lock timer expires.");
-    println!("Type 'help' to see commands.");
-    println!();
+    // A rewound practice run is not recorded
+    if heist.rewound() {
+        println!("{}", tr!("game.practice_unrecorded"));
+        return result;
+    }
 
-    // Track last scan hint and code guess status
-    let mut last_scan: Option<String> = None;
-    let mut code_cracked = false;
-    let start_time = Instant::now(); // Start timer
+    // Award experience whatever the outcome, keep unlocked achievements and add to lifetime stats
+    let xp = heist.xp_awards();
+    for (i, (profile, xp)) in profiles.iter_mut().zip(xp).enumerate() {
+        profile.achievements = heist.seats[i].achievements.clone();
+        if let Err(e) = Stats::record_run(&profile.handle, &heist.run_record(i)) {
+            println!("{}", tr!("game.save_stats", profile.handle, e));
+        }
+        print_lines(&profile.finish_mission(xp));
+        save_profile(profile);
+    }
+    result
+}
 
+// Turn loop until the heist is decided
+pub fn play(heist: &mut Heist) -> GameResult {
     loop {
-        // Check for loss conditions
-        if system.lock_timer <= 0 {
-            return GameResult::Failure("Alarm triggered — you were detected!".to_string());
-        }
-        if player.energy <= 0 {
-            return GameResult::Failure("You ran out of energy!".to_string());
+        // Check loss conditions and show the turn status
+        let outcome = heist.begin_turn();
+        print_lines(&outcome.lines);
+        if let Some(result) = heist.take_result() {
+            return result;
         }
 
-        // Display turn status
-        println!("\n--- Turn status ---");
-        println!(
-            "Lock timer: {} | Energy: {} | Hacks: {} | BruteUsed: {}",
-            system.lock_timer, player.energy, player.hacks_done, player.brute_force_used
-        );
-        println!(
-            "Firewall compromised: {}/{}",
-            system.compromised_nodes(),
-            system.firewall.len()
-        );
-        println!("Actions: scan(s), hack(h), brute(b), status, help, retreat(r)");
         print!("> ");
         io::stdout().flush().ok();
 
         // Read player input
         let input = match read_input() {
             Ok(s) => s,
-            Err(_) => return GameResult::Failure("Input error".to_string()),
+            Err(_) => return GameResult::Failure(tr!("game.input_error")),
         };
 
+        // Sysadmin's turn at the shared keyboard
+        if heist.defending() {
+            let outcome = match input.parse::<DefenseMove>() {
+                Ok(mv) => heist.defend(mv),
+                Err(_) => {
+                    println!("{}", tr!("game.unknown_move"));
+                    continue;
+                }
+            };
+            print_lines(&outcome.lines);
+            continue;
+        }
+
         // Parse input into Action enum
         let action = match input.parse::<Action>() {
+            Ok(Action::Hack) => match prompt_hack_target(heist) {
+                Some(a) => a,
+                None => continue,
+            },
             Ok(a) => a,
             Err(_) => {
-                println!("Unknown action. Type 'help' for options.");
+                println!("{}", tr!("game.unknown_action"));
                 continue;
             }
         };
 
-        match action {
-            // Show help
-            Action::Help => show_help(),
-
-            // Show player and system status
-            Action::Status => {
-                println!("Player: {:?}", player);
-                println!(
-                    "System: difficulty={:?}, timer={}, honeypot={}",
-                    system.difficulty, system.lock_timer, system.honeypot_active
-                );
-                display_firewall_map(&system.firewall, rows, cols);
-            }
+        let outcome = heist.apply(heist.turn, action);
+        print_lines(&outcome.lines);
+        if let Some(result) = heist.take_result() {
+            return result;
+        }
+    }
+}
 
-            // Retreat / exit
-            Action::Retreat => return GameResult::Failure("Retreated from the heist.".to_string()),
-
-            // Scan for hints
-            Action::Scan => {
-                let maybe_hint = system.scan(&mut player, last_scan.as_ref());
-                if let Some(h) = maybe_hint {
-                    println!("{}", h);
-                    last_scan = Some(h);
-                } else {
-                    println!("Scan failed / not enough energy.");
-                }
-                system.lock_timer -= 1;
-            }
+// Save a daily run (best score among the crew) and show the day's summary
+fn record_daily(daily: &Daily, heist: &Heist, result: &GameResult) {
+    let score = (0..heist.seats.len()).map(|i| heist.score(i)).max().unwrap_or(0);
+    let won = matches!(result, GameResult::Success);
+    let mut results = match DailyResults::load() {
+        Ok(r) => r,
+        Err(e) => {
+            println!("{}", tr!("game.read_daily", e));
+            return;
+        }
+    };
+    results.record(&daily.date, score, won);
+    if let Err(e) = results.save() {
+        println!("{}", tr!("game.save_daily", e));
+    }
+    print_lines(&results.summary_lines(daily));
+}
 
-            // Hack node or code
-            Action::Hack => {
-                println!("Hack target: (1) firewall node  (2) guess code");
-                print!("choose 1 or 2 > ");
-                io::stdout().flush().ok();
-
-                let choice = read_input().unwrap_or("1".to_string());
-
-                if choice.trim() == "2" {
-                    // Code guessing
-                    if let Some(guess) = read_code_guess() {
-                        let (cp, cw) = mastermind_feedback(&system.code_digits, &guess);
-                        println!("Feedback: {} correct pos, {} correct but wrong position", cp, cw);
-                        if cp == 4 {
-                            println!("You cracked the code!");
-                            code_cracked = true;
-                        } else {
-                            println!("Code guess failed.");
-                        }
-                        player.energy -= 6;
-                        system.lock_timer -= 1;
-                    }
-                } else {
-                    // Hack firewall node
-                    display_firewall_map(&system.firewall, rows, cols);
-                    println!("Enter coordinates as 'r c': ");
-
-                    let coords = read_input().unwrap_or_default();
-                    let parts: Vec<_> = coords.split_whitespace().collect();
-
-                    if parts.len() != 2 {
-                        println!("Invalid coords.");
-                        continue;
-                    }
-
-                    let r: usize = parts[0].parse().unwrap_or(99);
-                    let c: usize = parts[1].parse().unwrap_or(99);
-
-                    if r >= rows || c >= cols {
-                        println!("Coordinates out of range.");
-                        continue;
-                    }
-
-                    let idx = r * cols + c;
-
-                    let success = attempt_hack_node(&mut player, &mut system.firewall[idx]);
-                    if success {
-                        let reveal_roll = rand::thread_rng().gen_range(0..100);
-                        if reveal_roll < 20 {
-                            let pos = rand::thread_rng().gen_range(0..4);
-                            println!(
-                                "Node data leak: digit at position {} = {}",
-                                pos + 1,
-                                system.code_digits[pos]
-                            );
-                        }
-                    }
-
-                    system.lock_timer -= 1;
-                }
-            }
+// Offer the class menu; None keeps a generalist
+fn prompt_class() -> Option<OperatorClass> {
+    println!("{}", tr!("game.class"));
+    for class in CLASSES {
+        println!("  {}", class.describe());
+    }
+    read_input().ok().and_then(|s| s.parse::<OperatorClass>().ok())
+}
 
-            // Attempt brute force
-            Action::BruteForce => {
-                let success = system.brute_force(&mut player);
-                if success {
-                    code_cracked = true;
-                }
-                system.lock_timer -= 2;
-            }
-        };
+// Load a profile, reporting (but surviving) a read error
+fn load_profile(handle: &str) -> Profile {
+    let (profile, warning) = Profile::load_or_new(handle);
+    if let Some(w) = warning {
+        println!("{}", w);
+    }
+    profile
+}
 
-        // Check victory condition
-        if code_cracked && system.check_victory() {
-            let elapsed = start_time.elapsed();
-            println!("Success! Time taken: {:.2?}", elapsed);
-            return GameResult::Success;
+// Save a profile, reporting a write error
+fn save_profile(profile: &Profile) {
+    if let Err(e) = profile.save() {
+        println!("{}", tr!("game.save_profile", profile.handle, e));
+    }
+}
+
+// Show a profile and let the operator spend any skill points
+fn train_prompt(profile: &mut Profile) {
+    print_lines(&profile.summary_lines());
+    let mut trained = false;
+    while profile.points() > 0 {
+        println!("{}", tr!("game.train"));
+        let input = read_input().unwrap_or_default();
+        if input.trim().is_empty() {
+            break;
+        }
+        let Ok(skill) = input.parse::<Skill>() else {
+            println!("{}", tr!("game.unknown_skill"));
+            continue;
+        };
+        match profile.train(skill) {
+            Ok(rank) => {
+                println!("{}", tr!("game.rank", skill.label(), rank));
+                trained = true;
+            }
+            Err(msg) => println!("{}", msg),
         }
+    }
+    if trained {
+        save_profile(profile);
+    }
+}
 
-        // Apply penalties and regeneration
-        system.apply_explosion_penalty();
-        system.battery_regen(&mut player);
+// Ask what to hack when the player typed a bare "hack"
+fn prompt_hack_target(heist: &Heist) -> Option<Action> {
+    println!("{}", tr!("game.hack_target"));
+    print!("{} ", tr!("game.choose_target"));
+    io::stdout().flush().ok();
+
+    let choice = read_input().unwrap_or("1".to_string());
+
+    if choice.trim() == "2" {
+        // Attempt whichever vault lock is current
+        print_lines(&heist.lock_lines(heist.turn));
+        println!("{}", tr!("game.attempt"));
+        let attempt = read_input().unwrap_or_default();
+        return Some(Action::Guess(attempt));
     }
+
+    // Hack firewall node, with the odds of each one
+    print_lines(&heist.map_lines());
+    print_lines(&heist.odds_lines(heist.turn));
+    println!("{}", tr!("game.coords"));
+
+    let coords = read_input().unwrap_or_default();
+    let parts: Vec<_> = coords.split_whitespace().collect();
+
+    if parts.len() != 2 {
+        println!("{}", tr!("game.bad_coords"));
+        return None;
+    }
+
+    let r: usize = parts[0].parse().unwrap_or(99);
+    let c: usize = parts[1].parse().unwrap_or(99);
+    Some(Action::HackNode(r, c))
 }
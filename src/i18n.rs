@@ -0,0 +1,143 @@
+/*
+DATA TYPES USED:
+
+1. Catalog          -> Struct: the chosen language's messages plus the English fallback
+2. CATALOG          -> OnceLock<Catalog>, the catalog picked at startup
+3. LANGUAGES        -> [(&str, &str); 2], built-in language codes and their catalog files
+4. code             -> &str, language code ("en", "es", ...)
+5. key              -> &str, message key ("game.handle", "fw.success", ...)
+6. args             -> &[String], values filled into {0}, {1}, ...
+7. record           -> Record (from storage), key=value pairs of one catalog
+8. Result<(), String> -> Ok, or why the language could not be loaded
+9. template         -> &str, text with {0}, {1}, ... placeholders
+*/
+
+use std::fs;            // Read catalog files
+use std::sync::OnceLock; // Catalog chosen once at startup
+
+use crate::storage::{self, Record}; // Catalog format and data folder
+
+// Language used for missing keys
+pub const FALLBACK: &str = "en";
+
+// Catalogs built into the binary
+pub const LANGUAGES: [(&str, &str); 2] = [
+    ("en", include_str!("../lang/en.txt")),
+    ("es", include_str!("../lang/es.txt")),
+];
+
+// Struct holding the messages in use
+struct Catalog {
+    chosen: Record,  // Messages in the chosen language (may be incomplete)
+    english: Record, // Every message in English
+}
+
+static CATALOG: OnceLock<Catalog> = OnceLock::new();
+
+// Built-in catalog for a language code
+fn built_in(code: &str) -> Option<Record> {
+    LANGUAGES
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, text)| storage::parse_record(text))
+}
+
+// Pick the language; a file in <data dir>/lang/<code>.txt overrides or adds to the built-in one
+pub fn init(code: &str) -> Result<(), String> {
+    let code = code.trim().to_lowercase();
+    let english = built_in(FALLBACK).unwrap_or_default();
+    let file = storage::data_dir().join("lang").join(format!("{}.txt", storage::file_stem(&code)));
+
+    let (mut chosen, error) = match (fs::read_to_string(&file), built_in(&code)) {
+        (Ok(text), built) => {
+            let mut record = storage::parse_record(&text);
+            record.extend(built.unwrap_or_default()); // File entries come first and win
+            (record, None)
+        }
+        (Err(_), Some(record)) => (record, None),
+        (Err(_), None) => {
+            let known: Vec<&str> = LANGUAGES.iter().map(|(c, _)| *c).collect();
+            let msg = format!("Unknown language '{}' (built in: {}). Using English.", code, known.join(", "));
+            (Vec::new(), Some(msg))
+        }
+    };
+    chosen.retain(|(_, v)| !v.is_empty()); // An empty entry falls back like a missing one
+
+    CATALOG
+        .set(Catalog { chosen, english })
+        .map_err(|_| "Language already chosen.".to_string())?;
+    error.map_or(Ok(()), Err)
+}
+
+// Message for a key: chosen language, else English, else the key itself
+pub fn text(key: &str) -> String {
+    let catalog = CATALOG.get_or_init(|| Catalog {
+        chosen: Vec::new(),
+        english: built_in(FALLBACK).unwrap_or_default(),
+    });
+    storage::get(&catalog.chosen, key)
+        .or_else(|| storage::get(&catalog.english, key))
+        .unwrap_or(key)
+        .replace("\\n", "\n")
+}
+
+// Message with {0}, {1}, ... filled in
+pub fn fill(key: &str, args: &[String]) -> String {
+    format(&text(key), args)
+}
+
+// Fill {0}, {1}, ... in any text (catalog entries and content-pack text)
+pub fn format(template: &str, args: &[String]) -> String {
+    args.iter()
+        .enumerate()
+        .fold(template.to_string(), |msg, (i, arg)| msg.replace(&format!("{{{}}}", i), arg))
+}
+
+// tr!("key") or tr!("key", a, b, ...): a message in the chosen language
+macro_rules! tr {
+    ($key:expr) => {
+        $crate::i18n::text($key)
+    };
+    ($key:expr, $($arg:expr),+ $(,)?) => {
+        $crate::i18n::fill($key, &[$($arg.to_string()),+])
+    };
+}
+pub(crate) use tr;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Keys of a built-in catalog, in file order
+    fn keys(code: &str) -> Vec<String> {
+        built_in(code).unwrap().into_iter().map(|(k, _)| k).collect()
+    }
+
+    #[test]
+    fn built_in_catalogs_share_one_key_set() {
+        let english = keys(FALLBACK);
+        for (code, _) in LANGUAGES {
+            let mut sorted = keys(code);
+            sorted.sort();
+            let count = sorted.len();
+            sorted.dedup();
+            assert_eq!(sorted.len(), count, "{} repeats a key", code);
+
+            let missing: Vec<&String> = english.iter().filter(|k| !sorted.contains(k)).collect();
+            let extra: Vec<&String> = sorted.iter().filter(|k| !english.contains(k)).collect();
+            assert!(missing.is_empty() && extra.is_empty(), "{}: missing {:?}, extra {:?}", code, missing, extra);
+        }
+    }
+
+    #[test]
+    fn translations_use_the_english_placeholders() {
+        let placeholders = |text: &str| -> Vec<usize> { (0..10).filter(|i| text.contains(&format!("{{{}}}", i))).collect() };
+        let english = built_in(FALLBACK).unwrap();
+        for (code, _) in LANGUAGES {
+            for (key, text) in built_in(code).unwrap() {
+                let original = storage::get(&english, &key).unwrap_or_default();
+                assert_eq!(placeholders(&text), placeholders(original), "{} {}", code, key);
+            }
+        }
+    }
+}
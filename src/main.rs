@@ -2,34 +2,183 @@
 DATA TYPES USED:
 
 1.  GameResult    -> Enum: Success, Failure(String)
+2.  Launch        -> Enum: Play(RuleOptions), Tutorial(Style), Daily(Style), Server(ServerConfig), Connect(String), Profile(String, Option<Skill>), Achievements(String), Stats(String, Option<Difficulty>), Mods
+3.  args          -> Vec<String>, command-line arguments
+4.  profile       -> Profile struct, a saved operator
+5.  lang          -> Option<String>, language code from --lang
+6.  line          -> String, a content pack loaded or skipped
+7.  tr!           -> macro (from i18n), message text in the chosen language
 */
 
 mod player;    // Player module
 mod actions;   // Player action parsing module
 mod ui;        // User interface helpers
 mod utils;     // Utility functions (input, clamp, etc.)
-mod game;      // Main game logic
+mod engine;    // Game rules and state, free of terminal I/O
+mod game;      // Terminal game loop
+mod notebook;  // Guess history and code deduction
 mod security;  // Security system, firewall, and code modules
+mod net;       // TCP server and client
+mod cli;       // Command-line argument parsing
+mod energy;    // Energy cost table, regen and overcharge
+mod storage;   // Saved data on disk
+mod profile;   // Operator profiles, XP and skills
+mod mission;   // Seeded mission generator
+mod objectives; // Primary and optional objectives, score
+mod tutorial;  // Guided first mission
+mod daily;     // Date-seeded daily challenge
+mod achievements; // Unlockable feats
+mod stats;     // Lifetime stats per operator
+mod theme;     // Terminal color themes and screen-reader output
+mod i18n;      // Message catalogs and --lang
+mod content;   // Content packs: node types, items, events, hints, difficulty profiles
+mod script;    // Rhai mission scripts
 
-use game::run_game;      // Import main game function
-use game::GameResult;    // Import game result enum
+use achievements::checklist;          // Achievement list
+use cli::{parse_args, take_lang, usage, Launch}; // Command-line parsing
+use engine::GameResult;               // Import game result enum
+use i18n::tr;                         // Translated text
+use game::{run_daily, run_game};      // Import main game functions
+use net::client::run_client;          // TCP client
+use net::server::run_server;          // TCP server
+use profile::{Profile, Skill};        // Operator profiles
+use stats::Stats;                     // Lifetime stats
+use tutorial::run_tutorial;           // Guided first mission
 
-fn main() {
-    // Run the game and handle the outcome
-    match run_game() {
+// Print the final outcome of a heist
+fn report(result: GameResult) {
+    match result {
         GameResult::Success => {
             // Player succeeded
-            println!("\n--- MISSION ACCOMPLISHED: VAULT OPENED ---");
-            println!("Congratulations!");
+            println!("\n{}", tr!("main.success"));
+            println!("{}", tr!("main.congrats"));
         }
         GameResult::Failure(reason) => {
             // Player failed
-            println!("\n--- MISSION FAILED ---");
-            println!("Reason: {}", reason);
-            println!("Try again or review tactics.");
+            println!("\n{}", tr!("main.failed"));
+            println!("{}", tr!("main.reason", reason));
+            println!("{}", tr!("main.try_again"));
+        }
+    }
+}
+
+// Show a saved operator, spending a skill point first if asked
+fn show_profile(handle: &str, train: Option<Skill>) {
+    let mut profile = match Profile::load(handle) {
+        Ok(p) => p,
+        Err(e) => {
+            println!("{}", tr!("main.read_profile", handle, e));
+            return;
+        }
+    };
+    if let Some(skill) = train {
+        match profile.train(skill) {
+            Ok(rank) => match profile.save() {
+                Ok(()) => println!("{}", tr!("main.trained", skill.label(), rank)),
+                Err(e) => println!("{}", tr!("main.save_profile", handle, e)),
+            },
+            Err(msg) => println!("{}", msg),
+        }
+    }
+    for line in profile.summary_lines() {
+        println!("{}", line);
+    }
+}
+
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+
+    // Pick the language before any text is shown (unknown codes fall back to English)
+    let lang = match take_lang(&mut args) {
+        Ok(lang) => lang,
+        Err(msg) => {
+            println!("{}", msg);
+            println!("{}", usage());
+            return;
+        }
+    };
+    if let Some(code) = lang
+        && let Err(msg) = i18n::init(&code)
+    {
+        println!("{}", msg);
+    }
+
+    // Load content packs before anything is generated; broken packs are reported and skipped
+    for line in content::load(&content::mod_dirs()) {
+        println!("{}", line);
+    }
+
+    match parse_args(&args) {
+        // Run the game and handle the outcome
+        Ok(Launch::Play(rules)) => report(run_game(rules)),
+
+        // Guided first mission
+        Ok(Launch::Tutorial(style)) => report(run_tutorial(style)),
+
+        // Today's shared challenge
+        Ok(Launch::Daily(style)) => report(run_daily(style)),
+
+        // Host a heist over TCP
+        Ok(Launch::Server(config)) => match run_server(config) {
+            Ok(result) => report(result),
+            Err(e) => println!("{}", tr!("main.server_error", e)),
+        },
+
+        // Join a hosted heist
+        Ok(Launch::Connect(addr)) => {
+            if let Err(e) = run_client(&addr) {
+                println!("{}", tr!("main.connection_error", e));
+            }
+        }
+
+        // Lifetime stats, no heist
+        Ok(Launch::Stats(handle, difficulty)) => {
+            match Stats::load(&handle) {
+                Ok(stats) => {
+                    for line in stats.summary_lines(difficulty) {
+                        println!("{}", line);
+                    }
+                }
+                Err(e) => println!("{}", tr!("main.read_stats", handle, e)),
+            }
+            return;
+        }
+
+        // Achievement list, no heist
+        Ok(Launch::Achievements(handle)) => {
+            match Profile::load(&handle) {
+                Ok(profile) => {
+                    println!("{}", tr!("main.operator", profile.handle));
+                    for line in checklist(&profile.achievements) {
+                        println!("{}", line);
+                    }
+                }
+                Err(e) => println!("{}", tr!("main.read_profile", handle, e)),
+            }
+            return;
+        }
+
+        // Loaded content, no heist
+        Ok(Launch::Mods) => {
+            for line in content::get().summary_lines() {
+                println!("{}", line);
+            }
+            return;
+        }
+
+        // Profile screen, no heist
+        Ok(Launch::Profile(handle, train)) => {
+            show_profile(&handle, train);
+            return;
+        }
+
+        Err(msg) => {
+            println!("{}", msg);
+            println!("{}", usage());
+            return;
         }
     }
 
     // End of game message
-    println!("Thanks for playing CyberHeist!");
+    println!("{}", tr!("main.thanks"));
 }
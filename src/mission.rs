@@ -0,0 +1,187 @@
+/*
+DATA TYPES USED:
+
+1. Mission          -> Struct: codename, target, briefing and secondary objectives for one run
+2. Bonus            -> Struct (from objectives): an optional objective and the XP it pays
+3. data_stores      -> Vec<(usize, usize)>, nodes the DataStores objective asks for
+4. seed             -> u64, number every part of the mission is derived from
+5. rng              -> StdRng, seeded random number generator
+6. rows, cols       -> usize, firewall size (the exfiltration node must fit)
+7. lines            -> Vec<String>, briefing text
+8. tr!              -> macro (from i18n), message text in the chosen language
+*/
+
+use rand::rngs::StdRng;     // Seeded generator
+use rand::seq::{index, SliceRandom}; // Pick from word lists and grid cells
+use rand::{Rng, SeedableRng};
+
+use crate::i18n::tr; // Translated text
+use crate::objectives::{Bonus, Objective}; // Objectives and rewards
+use crate::security::Difficulty;           // Difficulty enum
+
+// Word lists the generator draws from (sites, prizes and rumours are catalog keys)
+const CORPS: [&str; 10] = [
+    "Helix Biotech",
+    "Obsidian Trust Bank",
+    "Kuroda Heavy Industries",
+    "Vantablack Logistics",
+    "Meridian Data Exchange",
+    "Solace Pharmaceuticals",
+    "Argent Defense Systems",
+    "Northwind Casino Group",
+    "Halcyon Orbital",
+    "Greyline Insurance",
+];
+const SITES: [&str; 8] = [
+    "mission.site_cold_storage",
+    "mission.site_rnd_farm",
+    "mission.site_data_haven",
+    "mission.site_archive",
+    "mission.site_mainframe",
+    "mission.site_black_site",
+    "mission.site_backbone",
+    "mission.site_uplink",
+];
+const PRIZES: [&str; 8] = [
+    "mission.prize_weights",
+    "mission.prize_ledger",
+    "mission.prize_master_keys",
+    "mission.prize_trial_results",
+    "mission.prize_blackmail",
+    "mission.prize_implant",
+    "mission.prize_client_list",
+    "mission.prize_bonds",
+];
+const FIXERS: [&str; 6] = ["Mother", "Kestrel", "Dr. Lumen", "The Broker", "Halfjack", "Saint"];
+const RUMOURS: [&str; 6] = [
+    "mission.rumour_jumpy_ids",
+    "mission.rumour_patched",
+    "mission.rumour_pen_testers",
+    "mission.rumour_night_shift",
+    "mission.rumour_rival_crew",
+    "mission.rumour_cheap_locks",
+];
+const CODE_ADJECTIVES: [&str; 10] = [
+    "Glass", "Silent", "Iron", "Velvet", "Broken", "Neon", "Hollow", "Crimson", "Paper", "Static",
+];
+const CODE_NOUNS: [&str; 10] = [
+    "Viper", "Lantern", "Harbor", "Orchid", "Falcon", "Meridian", "Cipher", "Tide", "Wolf", "Echo",
+];
+
+// Energy an operator must finish above for the EnergyAbove objective
+pub const ENERGY_GOAL: i32 = 50;
+
+// Code guesses allowed by the CodeInGuesses objective
+pub const GUESS_GOAL: u32 = 3;
+
+// Struct describing one generated mission
+#[derive(Debug, Clone)]
+pub struct Mission {
+    pub seed: u64,            // Seed the mission was built from
+    pub codename: String,     // e.g. "Operation Glass Viper"
+    pub target: String,       // Corporation being robbed
+    pub site: String,         // Facility that holds the vault
+    pub prize: String,        // What the vault holds
+    pub fixer: String,        // Who hired the crew
+    pub rumour: String,       // Flavour line about the target
+    pub payout: u32,          // Fee promised by the fixer, in credits (flavour)
+    pub data_stores: Vec<(usize, usize)>, // Data-store nodes, for the DataStores objective
+    pub bonuses: Vec<Bonus>,  // Optional objectives, in briefing order
+}
+
+impl Mission {
+    // Build a mission from a seed; the same seed, difficulty and grid always give the same mission
+    pub fn generate(seed: u64, difficulty: Difficulty, rows: usize, cols: usize) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let pick = |list: &[&str], rng: &mut StdRng| list.choose(rng).copied().unwrap_or_default().to_string();
+
+        let codename = tr!(
+            "mission.codename",
+            pick(&CODE_ADJECTIVES, &mut rng),
+            pick(&CODE_NOUNS, &mut rng)
+        );
+        let target = pick(&CORPS, &mut rng);
+        let site = tr!(&pick(&SITES, &mut rng));
+        let prize = tr!(&pick(&PRIZES, &mut rng));
+        let fixer = pick(&FIXERS, &mut rng);
+        let rumour = tr!(&pick(&RUMOURS, &mut rng));
+        let scale: u32 = match difficulty {
+            Difficulty::Easy => 1,
+            Difficulty::Normal => 2,
+            Difficulty::Hard => 3,
+        };
+        let payout = scale * rng.gen_range(20..60) * 1000;
+
+        // One data store per difficulty step, on distinct nodes
+        let data_stores = index::sample(&mut rng, rows * cols, scale as usize)
+            .into_iter()
+            .map(|i| (i / cols, i % cols))
+            .collect();
+
+        // Two optional objectives (three on hard), in random order
+        let mut pool = vec![
+            Objective::DataStores,
+            Objective::NoExplosions,
+            Objective::EnergyAbove(ENERGY_GOAL),
+            Objective::CodeInGuesses(GUESS_GOAL),
+            Objective::NoBruteForce,
+        ];
+        pool.shuffle(&mut rng);
+        pool.truncate(if matches!(difficulty, Difficulty::Hard) { 3 } else { 2 });
+        let bonuses = pool
+            .into_iter()
+            .map(|objective| Bonus {
+                objective,
+                xp: 5 * rng.gen_range(4..=10), // 20-50 XP
+            })
+            .collect();
+
+        Mission {
+            seed,
+            codename,
+            target,
+            site,
+            prize,
+            fixer,
+            rumour,
+            payout,
+            data_stores,
+            bonuses,
+        }
+    }
+
+    // Build a mission from a fresh random seed
+    pub fn random(difficulty: Difficulty, rows: usize, cols: usize) -> Self {
+        Mission::generate(rand::thread_rng().r#gen(), difficulty, rows, cols)
+    }
+
+    // Drop objectives about the code lock when the vault has none
+    pub fn drop_code_objectives(&mut self) {
+        self.bonuses
+            .retain(|b| !matches!(b.objective, Objective::CodeInGuesses(_) | Objective::NoBruteForce));
+    }
+
+    // Data-store nodes, if the mission asks for them
+    pub fn active_data_stores(&self) -> &[(usize, usize)] {
+        if self.bonuses.iter().any(|b| b.objective == Objective::DataStores) {
+            &self.data_stores
+        } else {
+            &[]
+        }
+    }
+
+    // Briefing shown at the start
+    pub fn briefing_lines(&self) -> Vec<String> {
+        let mut lines = vec![
+            tr!("mission.title", self.codename, self.seed),
+            tr!("mission.job", self.fixer, self.prize, self.site, self.target),
+            self.rumour.clone(),
+            tr!("mission.fee", self.payout),
+            tr!("mission.optional"),
+        ];
+        for bonus in &self.bonuses {
+            lines.push(format!("  - {}", tr!("mission.bonus", bonus.objective.describe(&self.data_stores), bonus.xp)));
+        }
+        lines
+    }
+}
@@ -0,0 +1,53 @@
+/*
+DATA TYPES USED:
+
+1. addr             -> &str, server address (host:port)
+2. stream           -> TcpStream, connection to the server
+3. upstream         -> TcpStream, clone used by the input thread
+4. line             -> String, one line of text from server or keyboard
+5. io::Result<()>   -> Result type for network errors
+6. tr!              -> macro (from i18n), message text in the chosen language
+*/
+
+use std::io::{self, BufRead, BufReader, Write}; // Stream reading / writing
+use std::net::TcpStream;                         // TCP networking
+use std::thread;                                 // Keyboard thread
+
+use super::{END, TURN}; // Protocol control lines
+use crate::i18n::tr;    // Translated text
+
+// Join a hosted heist and relay text between the keyboard and the server
+pub fn run_client(addr: &str) -> io::Result<()> {
+    let stream = TcpStream::connect(addr)?;
+    println!("{}", tr!("net.connected", addr));
+
+    // Keyboard lines go straight to the server
+    let mut upstream = stream.try_clone()?;
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines().map_while(Result::ok) {
+            if writeln!(upstream, "{}", line.trim()).is_err() {
+                break;
+            }
+        }
+    });
+
+    // Server lines are printed, control lines handled
+    for line in BufReader::new(stream).lines() {
+        let line = line?;
+        if line == TURN {
+            print!("> ");
+            io::stdout().flush().ok();
+        } else if let Some(rest) = line.strip_prefix(END) {
+            match rest.trim().strip_prefix("failure:") {
+                Some(reason) => println!("{}", tr!("net.over_failure", reason.trim())),
+                None => println!("{}", tr!("net.over_success")),
+            }
+            return Ok(());
+        } else {
+            println!("{}", line);
+        }
+    }
+
+    println!("{}", tr!("net.closed"));
+    Ok(())
+}
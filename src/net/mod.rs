@@ -0,0 +1,18 @@
+/*
+DATA TYPES USED:
+
+1. DEFAULT_ADDR     -> &str, address used when none is given
+2. TURN, END        -> &str, control lines of the text protocol
+*/
+
+pub mod client; // `cyberheist connect`: terminal client
+pub mod server; // `cyberheist server`: hosts a heist over TCP
+
+// Address used by both sides when none is given
+pub const DEFAULT_ADDR: &str = "127.0.0.1:7878";
+
+// Control line: the server is waiting for this client's command
+pub const TURN: &str = "@turn";
+
+// Control line prefix: the heist is over, followed by the result
+pub const END: &str = "@end";
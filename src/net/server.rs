@@ -0,0 +1,313 @@
+/*
+DATA TYPES USED:
+
+1. ServerConfig     -> Struct: address, operator count, difficulty, play mode
+2. listener         -> TcpListener, accepts client connections
+3. writers          -> Vec<TcpStream>, one outgoing stream per seat
+4. names            -> Vec<String>, handles sent by the clients
+5. ClientLine       -> Struct: seat index and a line (None when the client hung up)
+6. tx, rx           -> mpsc Sender / Receiver, lines from all reader threads
+7. heist            -> Heist struct, the game engine
+8. outcome          -> Outcome struct, text produced by the engine
+9. action           -> Action enum, parsed client command
+10. io::Result<T>   -> Result type for network errors
+11. sysadmin        -> usize, connection index of a human sysadmin (after all seats)
+12. profiles        -> Vec<Profile>, operator profiles kept on the host, one per seat
+13. classes         -> Vec<Option<OperatorClass>>, archetype picked by each operator
+14. script          -> Option<MissionScript>, Rhai mission hooks from --script (run on the host)
+15. JOIN_TIMEOUT    -> Duration, how long a joining client may take to answer a setup question
+16. tr!             -> macro (from i18n), message text in the chosen language
+*/
+
+use std::io::{self, BufRead, BufReader, Write}; // Stream reading / writing
+use std::net::{TcpListener, TcpStream};          // TCP networking
+use std::path::Path;                              // Mission script location
+use std::sync::mpsc;                              // Channel from reader threads
+use std::thread;                                  // One reader thread per client
+use std::time::Duration;                          // Setup read timeout
+
+use super::{END, TURN};                             // Protocol control lines
+use crate::actions::Action;                         // Enum for player actions
+use crate::engine::{GameResult, Heist, Outcome, PlayMode, RuleOptions}; // Game engine
+use crate::i18n::tr;                                // Translated text
+use crate::player::{OperatorClass, CLASSES};        // Operator archetypes
+use crate::profile::Profile;                        // Operator profiles
+use crate::script::MissionScript;                   // Mission script hooks
+use crate::stats::Stats;                            // Lifetime stats
+use crate::security::Difficulty;                    // Difficulty enum
+use crate::security::defense::{Defender, DefenderKind, DefenseMove}; // Sysadmin role
+
+// Settings for a hosted heist
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    pub addr: String,           // Address to listen on, e.g. 127.0.0.1:7878
+    pub players: usize,         // Operators to wait for before starting
+    pub difficulty: Difficulty, // Shared difficulty
+    pub mode: PlayMode,         // Competitive or cooperative
+    pub defender: Option<DefenderKind>, // Sysadmin role: none, AI or a human client
+    pub rules: RuleOptions,             // Rule overrides (e.g. vault rotation)
+}
+
+// A joining client that stays silent this long is dropped so others can join
+const JOIN_TIMEOUT: Duration = Duration::from_secs(60);
+
+// A line received from one client
+struct ClientLine {
+    seat: usize,          // Seat that sent the line
+    line: Option<String>, // None when the connection closed
+}
+
+// Send lines to one client, ignoring clients that already hung up
+fn send(stream: &mut TcpStream, lines: &[String]) {
+    for line in lines {
+        let _ = writeln!(stream, "{}", line);
+    }
+    let _ = stream.flush();
+}
+
+// Read one setup answer; None if the client hung up, errored or timed out
+fn ask(reader: &mut BufReader<TcpStream>) -> Option<String> {
+    let mut line = String::new();
+    match reader.read_line(&mut line) {
+        Ok(0) | Err(_) => None,
+        Ok(_) => Some(line.trim().to_string()),
+    }
+}
+
+// Deliver an engine outcome: private lines to the actor, announcements to everyone else
+fn deliver(writers: &mut [TcpStream], actor: usize, outcome: &Outcome) {
+    for (seat, stream) in writers.iter_mut().enumerate() {
+        if seat == actor {
+            send(stream, &outcome.lines);
+        } else {
+            send(stream, &outcome.announce);
+        }
+    }
+}
+
+// Host a heist: wait for every operator, then run turns until the heist is decided
+pub fn run_server(config: ServerConfig) -> io::Result<GameResult> {
+    // Load the mission script before anyone connects
+    let script = match config.rules.script.as_deref().map(|p| MissionScript::load(Path::new(p))).transpose() {
+        Ok(script) => script,
+        Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidInput, e)),
+    };
+
+    let listener = TcpListener::bind(&config.addr)?;
+    println!(
+        "{}",
+        tr!("net.hosting", listener.local_addr()?, config.players, config.mode.label(), config.difficulty.label())
+    );
+
+    // A human sysadmin is the last client to connect
+    let sysadmin = config.players;
+    let clients = config.players + usize::from(config.defender == Some(DefenderKind::Human));
+
+    // Accept clients and ask each for a handle
+    let mut writers: Vec<TcpStream> = Vec::with_capacity(clients);
+    let mut readers: Vec<BufReader<TcpStream>> = Vec::with_capacity(clients);
+    let mut names: Vec<String> = Vec::with_capacity(clients);
+    let mut profiles: Vec<Profile> = Vec::with_capacity(config.players);
+    let mut classes: Vec<Option<OperatorClass>> = Vec::with_capacity(config.players);
+    while writers.len() < clients {
+        // A failed connection is skipped; the server keeps accepting
+        let (mut stream, peer) = match listener.accept() {
+            Ok(conn) => conn,
+            Err(e) => {
+                println!("{}", tr!("net.accept_failed", e));
+                continue;
+            }
+        };
+        let mut reader = match stream.try_clone().and_then(|s| s.set_read_timeout(Some(JOIN_TIMEOUT)).map(|_| s)) {
+            Ok(s) => BufReader::new(s),
+            Err(e) => {
+                println!("{}", tr!("net.peer_failed", peer, e));
+                continue;
+            }
+        };
+        if writers.len() == sysadmin {
+            send(&mut stream, &[tr!("net.handle_sysadmin")]);
+        } else {
+            send(&mut stream, &[tr!("net.handle")]);
+        }
+
+        let Some(name) = ask(&mut reader) else {
+            println!("{}", tr!("net.no_handle", peer));
+            continue;
+        };
+        let name = match name.as_str() {
+            "" if writers.len() == sysadmin => tr!("net.default_sysadmin"),
+            "" => tr!("net.default_operator", writers.len() + 1),
+            n => n.to_string(),
+        };
+
+        // Operators' profiles live on the host; skill points are spent with `cyberheist profile`
+        let mut operator = None;
+        if writers.len() != sysadmin {
+            let (profile, warning) = Profile::load_or_new(&name);
+            if let Some(w) = warning {
+                println!("{}", w);
+            }
+            send(&mut stream, &profile.summary_lines());
+
+            let mut menu = vec![tr!("game.class")];
+            menu.extend(CLASSES.iter().map(|c| format!("  {}", c.describe())));
+            send(&mut stream, &menu);
+            let Some(choice) = ask(&mut reader) else {
+                println!("{}", tr!("net.no_class", name));
+                continue;
+            };
+            operator = Some((profile, choice.parse::<OperatorClass>().ok()));
+        }
+
+        // Setup is over: turns may take as long as they take
+        if let Err(e) = reader.get_ref().set_read_timeout(None) {
+            println!("{}", tr!("net.dropped", name, e));
+            continue;
+        }
+        if let Some((profile, class)) = operator {
+            profiles.push(profile);
+            classes.push(class);
+        }
+        println!("{}", tr!("net.joined_from", name, peer));
+
+        let waiting = clients - writers.len() - 1;
+        send(&mut stream, &[tr!("net.welcome", name, waiting)]);
+        for other in writers.iter_mut() {
+            send(other, &[tr!("net.joined", name)]);
+        }
+
+        writers.push(stream);
+        readers.push(reader);
+        names.push(name);
+    }
+
+    // One thread per client forwards its lines into a single channel
+    let (tx, rx) = mpsc::channel::<ClientLine>();
+    for (seat, reader) in readers.into_iter().enumerate() {
+        let tx = tx.clone();
+        thread::spawn(move || {
+            for line in reader.lines() {
+                match line {
+                    Ok(l) => {
+                        if tx.send(ClientLine { seat, line: Some(l) }).is_err() {
+                            return;
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+            let _ = tx.send(ClientLine { seat, line: None });
+        });
+    }
+    drop(tx);
+
+    let defender = match config.defender {
+        Some(DefenderKind::Human) => Some(Defender::new(&names[sysadmin], DefenderKind::Human)),
+        Some(DefenderKind::Ai) => Some(Defender::new("ICE", DefenderKind::Ai)),
+        None => None,
+    };
+    let mut heist = Heist::new(&names[..config.players], config.difficulty, config.mode, defender);
+    heist.apply_rules(&config.rules);
+    if let Some(script) = script {
+        heist.set_script(script);
+    }
+    for ((seat, profile), class) in heist.seats.iter_mut().zip(&profiles).zip(classes) {
+        seat.player.skills = profile.skills;
+        seat.achievements = profile.achievements.clone();
+        if let Some(class) = class {
+            seat.player.set_class(class);
+        }
+    }
+    let intro = heist.intro_lines();
+    for stream in writers.iter_mut() {
+        send(stream, &intro);
+    }
+
+    let result = loop {
+        // Check loss conditions and show the turn status
+        let outcome = heist.begin_turn();
+        deliver(&mut writers, heist.actor(), &outcome);
+        if let Some(result) = heist.take_result() {
+            break result;
+        }
+
+        let turn = heist.actor();
+        let name = heist.actor_name();
+        println!("{}", tr!("net.turn", name));
+        send(&mut writers[turn], &[TURN.to_string()]);
+        for (seat, stream) in writers.iter_mut().enumerate() {
+            if seat != turn {
+                send(stream, &[tr!("net.waiting", name)]);
+            }
+        }
+
+        // Wait until the current operator's turn is over
+        loop {
+            let msg = match rx.recv() {
+                Ok(m) => m,
+                Err(_) => return Ok(GameResult::Failure(tr!("net.all_gone"))),
+            };
+
+            let outcome = match msg.line {
+                None if msg.seat == sysadmin => {
+                    println!("{}", tr!("net.disconnected", names[msg.seat]));
+                    heist.automate_defender()
+                }
+                None => {
+                    println!("{}", tr!("net.disconnected", names[msg.seat]));
+                    heist.drop_out(msg.seat, &tr!("heist.disconnected"))
+                }
+                Some(line) if msg.seat == sysadmin => match line.parse::<DefenseMove>() {
+                    Ok(mv) => heist.defend(mv),
+                    Err(_) => Outcome {
+                        lines: vec![tr!("game.unknown_move")],
+                        ..Outcome::default()
+                    },
+                },
+                Some(line) => match line.parse::<Action>() {
+                    Ok(action) => heist.apply(msg.seat, action),
+                    Err(_) => Outcome {
+                        lines: vec![tr!("game.unknown_action")],
+                        ..Outcome::default()
+                    },
+                },
+            };
+            deliver(&mut writers, msg.seat, &outcome);
+
+            if outcome.turn_over || heist.actor() != turn {
+                break;
+            }
+            if msg.seat == turn {
+                send(&mut writers[turn], &[TURN.to_string()]); // Still this operator's turn
+            }
+        }
+
+        if let Some(result) = heist.take_result() {
+            break result;
+        }
+    };
+
+    // Award experience, keep unlocked achievements and save each operator's profile and stats on the host
+    let xp = heist.xp_awards();
+    for (i, ((profile, xp), stream)) in profiles.iter_mut().zip(xp).zip(writers.iter_mut()).enumerate() {
+        profile.achievements = heist.seats[i].achievements.clone();
+        if let Err(e) = Stats::record_run(&profile.handle, &heist.run_record(i)) {
+            println!("{}", tr!("game.save_stats", profile.handle, e));
+        }
+        send(stream, &profile.finish_mission(xp));
+        if let Err(e) = profile.save() {
+            println!("{}", tr!("game.save_profile", profile.handle, e));
+        }
+    }
+
+    // Tell every client how it ended
+    let summary = match &result {
+        GameResult::Success => format!("{} success", END),
+        GameResult::Failure(reason) => format!("{} failure: {}", END, reason),
+    };
+    for stream in writers.iter_mut() {
+        send(stream, std::slice::from_ref(&summary));
+    }
+    Ok(result)
+}
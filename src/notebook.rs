@@ -0,0 +1,228 @@
+/*
+DATA TYPES USED:
+
+1. GuessRecord      -> Struct: a code guess and the feedback it received
+2. guess            -> [u8; 4], digits that were guessed
+3. correct          -> usize, digits correct in value & position
+4. misplaced        -> usize, digits correct but in wrong position
+5. Notebook         -> Struct: guess history and known code clues
+6. guesses          -> Vec<GuessRecord>, every guess in order
+7. clues            -> Vec<KnownClue>, leaked digits and scan hints
+8. code             -> [u8; 4], candidate code being tested
+9. candidates       -> Vec<[u8; 4]>, codes consistent with all notes
+10. possible        -> [Vec<u8>; 4], digits still possible per position
+11. lines           -> Vec<String>, report text for the operator
+12. KnownClue       -> Struct: a clue and the code epoch it was learned in
+13. epoch           -> u32, number of vault re-keys at the time of a note
+14. stale           -> Vec<GuessRecord>, guesses set aside after a re-key
+15. rekeys          -> &[Vec<usize>], positions changed by each re-key so far
+16. tr!             -> macro (from i18n), message text in the chosen language
+*/
+
+use crate::i18n::tr; // Translated text
+use crate::security::code::{mastermind_feedback, CodeClue}; // Feedback function and clue enum
+
+// Number of remaining candidates small enough to list in full
+const LIST_LIMIT: usize = 10;
+
+// Struct recording one code guess and its feedback
+#[derive(Debug, Clone, Copy)]
+pub struct GuessRecord {
+    pub guess: [u8; 4],   // Digits that were guessed
+    pub correct: usize,   // Correct digit in correct position
+    pub misplaced: usize, // Correct digit in wrong position
+    pub epoch: u32,       // Code epoch the guess was made against
+}
+
+// Struct recording one clue about the code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KnownClue {
+    pub clue: CodeClue, // What is known
+    pub epoch: u32,     // Code epoch the clue was learned in
+}
+
+// Struct keeping the player's notes about the vault code
+#[derive(Debug, Clone, Default)]
+pub struct Notebook {
+    pub guesses: Vec<GuessRecord>, // Guess history in order
+    pub clues: Vec<KnownClue>,     // Leaked digits and scan hints
+    pub stale: Vec<GuessRecord>,   // Guesses made before a re-key
+    pub epoch: u32,                // Latest re-key the operator has heard of
+}
+
+impl Notebook {
+    // Constructor for an empty notebook
+    pub fn new() -> Self {
+        Notebook::default()
+    }
+
+    // Store a guess together with its feedback
+    pub fn record_guess(&mut self, guess: [u8; 4], feedback: (usize, usize), epoch: u32) {
+        self.guesses.push(GuessRecord {
+            guess,
+            correct: feedback.0,
+            misplaced: feedback.1,
+            epoch,
+        });
+    }
+
+    // Store a clue, skipping exact duplicates
+    pub fn record_clue(&mut self, clue: CodeClue, epoch: u32) {
+        let known = KnownClue { clue, epoch };
+        if !self.clues.contains(&known) {
+            self.clues.push(known);
+        }
+    }
+
+    // After hearing of re-keys, drop notes the new digits have made wrong.
+    // rekeys[i] lists the positions changed by re-key number i + 1.
+    pub fn set_aside(&mut self, rekeys: &[Vec<usize>]) -> usize {
+        let changed_since = |epoch: u32| -> Vec<usize> {
+            rekeys.iter().skip(epoch as usize).flatten().copied().collect()
+        };
+        let before = self.guesses.len() + self.clues.len();
+
+        // Feedback covers every position, so any change spoils a guess
+        let (fresh, stale): (Vec<GuessRecord>, Vec<GuessRecord>) = self
+            .guesses
+            .iter()
+            .partition(|g| changed_since(g.epoch).is_empty());
+        self.guesses = fresh;
+        self.stale.extend(stale);
+
+        // Digit clues survive if their own position was not re-keyed
+        self.clues.retain(|k| {
+            let changed = changed_since(k.epoch);
+            match k.clue.position() {
+                Some(pos) => !changed.contains(&pos),
+                None => changed.is_empty(),
+            }
+        });
+
+        self.epoch = rekeys.len() as u32;
+        before - self.guesses.len() - self.clues.len()
+    }
+
+    // Check whether a code agrees with every guess and clue so far
+    pub fn is_consistent(&self, code: &[u8; 4]) -> bool {
+        let guesses_ok = self
+            .guesses
+            .iter()
+            .all(|g| mastermind_feedback(code, &g.guess) == (g.correct, g.misplaced));
+        guesses_ok && self.clues.iter().all(|k| k.clue.matches(code))
+    }
+
+    // Enumerate all 10000 codes and keep the consistent ones
+    pub fn candidates(&self) -> Vec<[u8; 4]> {
+        (0..10000u16)
+            .map(|n| {
+                [
+                    (n / 1000) as u8,
+                    (n / 100 % 10) as u8,
+                    (n / 10 % 10) as u8,
+                    (n % 10) as u8,
+                ]
+            })
+            .filter(|code| self.is_consistent(code))
+            .collect()
+    }
+
+    // Guess history and known clues (used by the status screen)
+    pub fn history_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if self.guesses.is_empty() {
+            lines.push(tr!("note.no_history"));
+        } else {
+            lines.push(tr!("note.history"));
+            for (i, g) in self.guesses.iter().enumerate() {
+                lines.push(format!("  {}", tr!("note.guess", i + 1, format_code(&g.guess), g.correct, g.misplaced)));
+            }
+        }
+
+        if !self.stale.is_empty() {
+            lines.push(format!("  {}", tr!("note.stale", self.stale.len())));
+        }
+
+        if !self.clues.is_empty() {
+            let known: Vec<String> = self.clues.iter().map(|k| k.clue.describe()).collect();
+            lines.push(tr!("note.clues", known.join(", ")));
+        }
+        lines
+    }
+
+    // Report how many codes still fit the notes
+    pub fn analyze(&self) -> Vec<String> {
+        let candidates = self.candidates();
+        let mut lines = Vec::new();
+
+        lines.push(tr!("note.analysis", candidates.len(), self.guesses.len(), self.clues.len()));
+
+        if candidates.is_empty() {
+            lines.push(tr!("note.no_fit"));
+            return lines;
+        }
+
+        // Digits still possible at each position
+        let mut possible: [Vec<u8>; 4] = Default::default();
+        for code in &candidates {
+            for (pos, digits) in possible.iter_mut().enumerate() {
+                if !digits.contains(&code[pos]) {
+                    digits.push(code[pos]);
+                }
+            }
+        }
+        for (pos, digits) in possible.iter_mut().enumerate() {
+            digits.sort();
+            let list: Vec<String> = digits.iter().map(|d| d.to_string()).collect();
+            lines.push(format!("  {}", tr!("note.position", pos + 1, list.join(" "))));
+        }
+
+        if candidates.len() <= LIST_LIMIT {
+            let list: Vec<String> = candidates.iter().map(format_code).collect();
+            lines.push(tr!("note.remaining", list.join(", ")));
+        }
+        lines
+    }
+}
+
+// Format a code as a 4-character string
+pub fn format_code(code: &[u8; 4]) -> String {
+    code.iter().map(|d| d.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guesses_and_clues_narrow_the_candidates() {
+        let mut notebook = Notebook::new();
+        assert_eq!(notebook.candidates().len(), 10000);
+
+        // No 1, 2, 3 or 4 anywhere: six digits left for each position
+        notebook.record_guess([1, 2, 3, 4], (0, 0), 0);
+        assert_eq!(notebook.candidates().len(), 6 * 6 * 6 * 6);
+
+        notebook.record_clue(CodeClue::DigitAt(0, 5), 0);
+        assert_eq!(notebook.candidates().len(), 6 * 6 * 6);
+
+        // Even second digit: 0, 6 or 8; a repeated clue changes nothing
+        notebook.record_clue(CodeClue::DigitParity(1, true), 0);
+        notebook.record_clue(CodeClue::DigitParity(1, true), 0);
+        assert_eq!(notebook.candidates().len(), 3 * 6 * 6);
+        assert!(notebook.is_consistent(&[5, 8, 9, 0]));
+        assert!(!notebook.is_consistent(&[5, 7, 9, 0]));
+    }
+
+    #[test]
+    fn rekeys_set_aside_spoiled_notes() {
+        let mut notebook = Notebook::new();
+        notebook.record_guess([1, 2, 3, 4], (0, 0), 0);
+        notebook.record_clue(CodeClue::DigitAt(0, 5), 0);
+        notebook.record_clue(CodeClue::DigitParity(1, true), 0);
+
+        // Re-keying the second digit spoils the guess and the clue about it
+        assert_eq!(notebook.set_aside(&[vec![1]]), 2);
+        assert_eq!(notebook.candidates().len(), 1000);
+    }
+}
@@ -0,0 +1,96 @@
+/*
+DATA TYPES USED:
+
+1. Objective        -> Enum: primary goals (ControlFirewall, OpenVault) and optional ones
+2. Progress         -> Enum: Open(String), Done, Failed, live state of one objective
+3. Bonus            -> Struct: an optional objective and the XP it pays
+4. needed, held     -> usize, nodes required / nodes held
+5. guesses          -> u32, code lock attempts so far
+6. reward           -> u32, XP for an optional objective (score counts it ten times)
+7. tr!              -> macro (from i18n), message text in the chosen language
+*/
+
+use crate::i18n::tr; // Translated text
+
+// Score for each primary objective met
+pub const PRIMARY_POINTS: u32 = 250;
+
+// Score per optional objective is its XP reward times this
+pub const BONUS_POINTS_PER_XP: u32 = 10;
+
+// Score per lock timer tick left when the vault opens
+pub const TIMER_POINTS: u32 = 20;
+
+// Enum for everything a mission can ask of the crew
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    ControlFirewall,    // Primary: hold 30% of the firewall
+    OpenVault,          // Primary: open every vault lock
+    DataStores,         // Hack every data-store node named in the briefing
+    NoExplosions,       // Never blow up a node
+    EnergyAbove(i32),   // Finish with more than this much energy
+    CodeInGuesses(u32), // Crack the code lock in this many guesses or fewer
+    NoBruteForce,       // Never brute force
+}
+
+// Live state of one objective
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Progress {
+    Open(String), // Still in play, with a progress note
+    Done,         // Met (for now, in the case of holdings)
+    Failed,       // Can no longer be met this mission
+}
+
+// An optional objective and its reward
+#[derive(Debug, Clone, Copy)]
+pub struct Bonus {
+    pub objective: Objective, // What to do
+    pub xp: u32,              // XP paid to each winner who manages it
+}
+
+impl Objective {
+    // Text shown in the briefing and the checklist
+    pub fn describe(&self, data_stores: &[(usize, usize)]) -> String {
+        match self {
+            Objective::ControlFirewall => tr!("obj.control_firewall"),
+            Objective::OpenVault => tr!("obj.open_vault"),
+            Objective::DataStores => {
+                let nodes: Vec<String> = data_stores.iter().map(|(r, c)| format!("({},{})", r, c)).collect();
+                tr!("obj.data_stores", nodes.join(" "))
+            }
+            Objective::NoExplosions => tr!("obj.no_explosions"),
+            Objective::EnergyAbove(n) => tr!("obj.energy_above", n),
+            Objective::CodeInGuesses(n) => tr!("obj.code_in_guesses", n),
+            Objective::NoBruteForce => tr!("obj.no_brute_force"),
+        }
+    }
+}
+
+impl Progress {
+    // Checklist mark
+    pub fn mark(&self) -> &'static str {
+        match self {
+            Progress::Open(_) => "[ ]",
+            Progress::Done => "[x]",
+            Progress::Failed => "[-]",
+        }
+    }
+
+    // Progress note, if any
+    pub fn note(&self) -> String {
+        match self {
+            Progress::Open(note) if !note.is_empty() => format!(" ({})", note),
+            Progress::Failed => format!(" ({})", tr!("obj.failed")),
+            _ => String::new(),
+        }
+    }
+}
+
+// Progress towards holding a number of nodes
+pub fn holding(held: usize, needed: usize) -> Progress {
+    if held >= needed {
+        Progress::Done
+    } else {
+        Progress::Open(format!("{}/{}", held, needed))
+    }
+}
@@ -9,36 +9,164 @@ DATA TYPES USED:
 6. inventory         -> Vec<String>, items held by the player
 7. position          -> (usize, usize), player's current grid position
 8. difficulty        -> Enum Difficulty: Easy, Normal, Hard
+9. id                -> usize, seat number used for node ownership
+10. rated_energy     -> i32, normal maximum energy (more is overcharge)
+11. skills           -> Skills, skill ranks loaded from the operator profile
+12. base             -> i32, energy cost before skills apply
+13. OperatorClass    -> Enum: Cracker, Infiltrator, Ghost, Engineer
+14. class            -> Option<OperatorClass>, archetype picked at mission start (None = generalist)
+15. items            -> Vec<String>, starting inventory (including content-pack items)
+16. tr!              -> macro (from i18n), message text in the chosen language
 */
 
+use crate::energy::rated_energy;  // Starting energy by difficulty
+use crate::content;               // Content-pack items
+use std::str::FromStr; // Parse class names
+
+use crate::profile::Skills;       // Skill ranks
 use crate::security::Difficulty; // Import difficulty enum
+use crate::i18n::tr;             // Translated text
+
+// Class perks
+pub const CRACKER_GUESS_DISCOUNT: i32 = 3; // Energy off each code lock guess
+pub const CRACKER_SCAN_DISCOUNT: i32 = 3;  // Energy off each targeted code scan
+pub const CRACKER_BRUTE_BONUS: i32 = 10;   // Brute force success, percentage points
+pub const INFILTRATOR_HACK_BONUS: i32 = 12; // Node hack success, percentage points
+pub const INFILTRATOR_HACK_DISCOUNT: i32 = 1; // Energy off each node hack
+pub const GHOST_EXPLODE_ABOVE: (u32, u32) = (70, 95); // Explosion roll needed (honeypot, other); normally (40, 85)
+pub const ENGINEER_RATED_BONUS: i32 = 15;  // Extra rated (and starting) energy
+pub const ENGINEER_BATTERY_CHANCE: f64 = 0.25; // Battery pack chance per turn; normally 7%
+
+// Enum for the operator archetypes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperatorClass {
+    Cracker,     // Code specialist
+    Infiltrator, // Firewall specialist
+    Ghost,       // Quiet and careful
+    Engineer,    // Power specialist
+}
+
+// Every class, in menu order
+pub const CLASSES: [OperatorClass; 4] = [
+    OperatorClass::Cracker,
+    OperatorClass::Infiltrator,
+    OperatorClass::Ghost,
+    OperatorClass::Engineer,
+];
+
+// Convert "cracker" / "i" etc. into OperatorClass
+impl FromStr for OperatorClass {
+    type Err = (); // Unit type () used as error
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "c" | "cracker" => Ok(OperatorClass::Cracker),
+            "i" | "infiltrator" => Ok(OperatorClass::Infiltrator),
+            "g" | "ghost" => Ok(OperatorClass::Ghost),
+            "e" | "engineer" => Ok(OperatorClass::Engineer),
+            _ => Err(()),
+        }
+    }
+}
+
+impl OperatorClass {
+    // English name, handed to scripts
+    pub fn name(&self) -> &'static str {
+        match self {
+            OperatorClass::Cracker => "Cracker",
+            OperatorClass::Infiltrator => "Infiltrator",
+            OperatorClass::Ghost => "Ghost",
+            OperatorClass::Engineer => "Engineer",
+        }
+    }
+
+    // Display name in the chosen language
+    pub fn label(&self) -> String {
+        match self {
+            OperatorClass::Cracker => tr!("class.cracker"),
+            OperatorClass::Infiltrator => tr!("class.infiltrator"),
+            OperatorClass::Ghost => tr!("class.ghost"),
+            OperatorClass::Engineer => tr!("class.engineer"),
+        }
+    }
+
+    // One-line summary of the perks, for the class menu
+    pub fn describe(&self) -> String {
+        let (key, perks) = match self {
+            OperatorClass::Cracker => ('c', tr!("class.cracker_perks")),
+            OperatorClass::Infiltrator => ('i', tr!("class.infiltrator_perks")),
+            OperatorClass::Ghost => ('g', tr!("class.ghost_perks")),
+            OperatorClass::Engineer => ('e', tr!("class.engineer_perks")),
+        };
+        format!("{:<11} ({}) - {}", self.label(), key, perks)
+    }
+}
 
 // Struct representing the player
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Player {
+    pub id: usize,               // Seat number (0-based), owner id for hacked nodes
     pub name: String,            // Player handle / name
     pub energy: i32,             // Current energy
+    pub rated_energy: i32,       // Normal maximum; above it the operator is overcharged
     pub hacks_done: u32,         // Number of successful hacks
     pub brute_force_used: u8,    // Number of brute force attempts used
     pub inventory: Vec<String>,  // Player's items
+    pub skills: Skills,          // Skill ranks from the operator profile
+    pub class: Option<OperatorClass>, // Archetype, if one was chosen
+}
+
+// Items every operator starts with: a USB exploit plus content-pack items marked start=yes
+fn starting_items() -> Vec<String> {
+    let mut items = vec!["USB exploit".to_string()];
+    items.extend(content::get().items.iter().filter(|i| i.start).map(|i| i.name.clone()));
+    items
 }
 
 impl Player {
     // Constructor for creating a new player
-    pub fn new(name: &str, difficulty: Difficulty) -> Self {
+    pub fn new(id: usize, name: &str, difficulty: Difficulty) -> Self {
         // Set initial energy based on game difficulty
-        let energy = match difficulty {
-            Difficulty::Easy => 120,
-            Difficulty::Normal => 100,
-            Difficulty::Hard => 80,
-        };
+        let energy = rated_energy(difficulty);
 
         Player {
+            id,                                   // Seat number
             name: name.to_string(),               // Convert &str to String
             energy,                               // Set initial energy
+            rated_energy: energy,                 // Start fully charged
             hacks_done: 0,                        // No hacks yet
             brute_force_used: 0,                  // No brute force used
-            inventory: vec!["USB exploit".to_string()], // Start with a basic item
+            inventory: starting_items(),          // A basic item plus any content-pack items
+            skills: Skills::default(),            // Untrained until a profile is loaded
+            class: None,                          // Generalist until a class is chosen
+        }
+    }
+
+    // Take on a class and its starting stats
+    pub fn set_class(&mut self, class: OperatorClass) {
+        self.class = Some(class);
+        if class == OperatorClass::Engineer {
+            self.rated_energy += ENGINEER_RATED_BONUS;
+            self.energy += ENGINEER_RATED_BONUS;
+            self.inventory.push("Battery pack".to_string());
+        }
+    }
+
+    // Whether the operator plays this class
+    pub fn is(&self, class: OperatorClass) -> bool {
+        self.class == Some(class)
+    }
+
+    // Name with the class, e.g. "Zed the Cracker"
+    pub fn title(&self) -> String {
+        match self.class {
+            Some(class) => tr!("class.title", self.name, class.label()),
+            None => self.name.clone(),
         }
     }
+
+    // Energy an action actually costs this operator
+    pub fn cost(&self, base: i32) -> i32 {
+        self.skills.discount(base)
+    }
 }
@@ -0,0 +1,263 @@
+/*
+DATA TYPES USED:
+
+1. Skill            -> Enum: Cryptanalysis, ExploitDev, Stealth, PowerManagement
+2. Skills           -> Struct: rank (0-3) in each skill
+3. Profile          -> Struct: an operator's saved handle, XP, missions and skills
+4. xp               -> u32, experience earned over all missions
+5. level            -> u32, 1 + one level per XP_PER_LEVEL
+6. points           -> u32, skill points earned but not yet spent
+7. amount, cost     -> u32 / i32, alert gain or energy cost before skills apply
+8. record           -> Record (from storage), key=value pairs on disk
+9. lines            -> Vec<String>, text shown to the operator
+10. achievements    -> Vec<Achievement>, feats the operator has unlocked
+11. tr!             -> macro (from i18n), message text in the chosen language
+*/
+
+use std::io;             // Error type for saving
+use std::path::PathBuf;  // Profile file location
+use std::str::FromStr;   // Parse skill names
+
+use crate::achievements::{self, Achievement}; // Unlockable feats
+use crate::i18n::tr; // Translated text
+use crate::storage::{self, Record}; // Saved data
+
+// XP needed for each level (every level grants one skill point)
+pub const XP_PER_LEVEL: u32 = 100;
+
+// Highest rank in any skill
+pub const MAX_RANK: u8 = 3;
+
+// Enum for the branches of the skill tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Skill {
+    Cryptanalysis,   // Sharper code scan hints
+    ExploitDev,      // Better odds when hacking nodes
+    Stealth,         // Less IDS alert from noisy actions
+    PowerManagement, // Cheaper actions
+}
+
+// Every skill, in display order
+pub const SKILLS: [Skill; 4] = [
+    Skill::Cryptanalysis,
+    Skill::ExploitDev,
+    Skill::Stealth,
+    Skill::PowerManagement,
+];
+
+// Convert "crypto" / "exploit" / "stealth" / "power" etc. into Skill
+impl FromStr for Skill {
+    type Err = (); // Unit type () used as error
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "c" | "crypto" | "cryptanalysis" => Ok(Skill::Cryptanalysis),
+            "e" | "exploit" | "exploit-dev" | "exploit_dev" => Ok(Skill::ExploitDev),
+            "s" | "stealth" => Ok(Skill::Stealth),
+            "p" | "power" | "power-management" | "power_management" => Ok(Skill::PowerManagement),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Skill {
+    // Key used in the profile file
+    fn key(&self) -> &'static str {
+        match self {
+            Skill::Cryptanalysis => "cryptanalysis",
+            Skill::ExploitDev => "exploit_dev",
+            Skill::Stealth => "stealth",
+            Skill::PowerManagement => "power_management",
+        }
+    }
+
+    // Display name
+    pub fn label(&self) -> String {
+        match self {
+            Skill::Cryptanalysis => tr!("profile.crypto"),
+            Skill::ExploitDev => tr!("profile.exploit"),
+            Skill::Stealth => tr!("profile.stealth"),
+            Skill::PowerManagement => tr!("profile.power"),
+        }
+    }
+
+    // Name and effect per rank, for the profile screen
+    pub fn describe(&self) -> String {
+        match self {
+            Skill::Cryptanalysis => tr!("profile.crypto_perk"),
+            Skill::ExploitDev => tr!("profile.exploit_perk"),
+            Skill::Stealth => tr!("profile.stealth_perk"),
+            Skill::PowerManagement => tr!("profile.power_perk"),
+        }
+    }
+}
+
+// Struct holding an operator's skill ranks
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Skills {
+    pub cryptanalysis: u8,    // Rank 0-3
+    pub exploit_dev: u8,      // Rank 0-3
+    pub stealth: u8,          // Rank 0-3
+    pub power_management: u8, // Rank 0-3
+}
+
+impl Skills {
+    // Rank in one skill
+    pub fn rank(&self, skill: Skill) -> u8 {
+        match skill {
+            Skill::Cryptanalysis => self.cryptanalysis,
+            Skill::ExploitDev => self.exploit_dev,
+            Skill::Stealth => self.stealth,
+            Skill::PowerManagement => self.power_management,
+        }
+    }
+
+    // Mutable rank in one skill
+    fn rank_mut(&mut self, skill: Skill) -> &mut u8 {
+        match skill {
+            Skill::Cryptanalysis => &mut self.cryptanalysis,
+            Skill::ExploitDev => &mut self.exploit_dev,
+            Skill::Stealth => &mut self.stealth,
+            Skill::PowerManagement => &mut self.power_management,
+        }
+    }
+
+    // Ranks bought so far
+    pub fn total(&self) -> u32 {
+        SKILLS.iter().map(|&s| self.rank(s) as u32).sum()
+    }
+
+    // Code clues weighed by a code scan (the best one is reported)
+    pub fn clue_samples(&self) -> usize {
+        1 + self.cryptanalysis as usize
+    }
+
+    // Hack success bonus, in percentage points
+    pub fn hack_bonus(&self) -> i32 {
+        5 * self.exploit_dev as i32
+    }
+
+    // IDS alert actually raised by a noisy action
+    pub fn alert_gain(&self, amount: u32) -> u32 {
+        amount - amount * self.stealth as u32 / 4
+    }
+
+    // Energy actually spent on an action
+    pub fn discount(&self, cost: i32) -> i32 {
+        (cost - (cost * self.power_management as i32 + 5) / 10).max(1)
+    }
+}
+
+// Struct for a saved operator
+#[derive(Debug, Clone)]
+pub struct Profile {
+    pub handle: String,  // Operator handle
+    pub xp: u32,         // Experience over all missions
+    pub missions: u32,   // Missions played
+    pub skills: Skills,  // Skill ranks bought with levels
+    pub achievements: Vec<Achievement>, // Feats unlocked so far
+}
+
+// Where a handle's profile is kept
+fn profile_path(handle: &str) -> PathBuf {
+    storage::data_dir()
+        .join("profiles")
+        .join(format!("{}.txt", storage::file_stem(handle)))
+}
+
+impl Profile {
+    // Load a profile, starting a fresh one if none is saved
+    pub fn load(handle: &str) -> io::Result<Self> {
+        let record = storage::read_record(&profile_path(handle))?;
+        let mut skills = Skills::default();
+        for skill in SKILLS {
+            *skills.rank_mut(skill) = (storage::get_u32(&record, skill.key()) as u8).min(MAX_RANK);
+        }
+        Ok(Profile {
+            handle: handle.to_string(),
+            xp: storage::get_u32(&record, "xp"),
+            missions: storage::get_u32(&record, "missions"),
+            skills,
+            achievements: achievements::parse_list(storage::get(&record, "achievements").unwrap_or_default()),
+        })
+    }
+
+    // Load a profile, falling back to a fresh one (the error is returned as a note)
+    pub fn load_or_new(handle: &str) -> (Self, Option<String>) {
+        match Profile::load(handle) {
+            Ok(profile) => (profile, None),
+            Err(e) => (
+                Profile {
+                    handle: handle.to_string(),
+                    xp: 0,
+                    missions: 0,
+                    skills: Skills::default(),
+                    achievements: Vec::new(),
+                },
+                Some(tr!("profile.read_error", handle, e)),
+            ),
+        }
+    }
+
+    // Write the profile to disk
+    pub fn save(&self) -> io::Result<()> {
+        let mut record: Record = vec![
+            ("handle".to_string(), self.handle.clone()),
+            ("xp".to_string(), self.xp.to_string()),
+            ("missions".to_string(), self.missions.to_string()),
+        ];
+        for skill in SKILLS {
+            record.push((skill.key().to_string(), self.skills.rank(skill).to_string()));
+        }
+        record.push(("achievements".to_string(), achievements::format_list(&self.achievements)));
+        storage::write_record(&profile_path(&self.handle), &record)
+    }
+
+    // Current level
+    pub fn level(&self) -> u32 {
+        1 + self.xp / XP_PER_LEVEL
+    }
+
+    // Skill points earned but not spent
+    pub fn points(&self) -> u32 {
+        (self.level() - 1).saturating_sub(self.skills.total())
+    }
+
+    // Spend a point on a skill; returns the new rank
+    pub fn train(&mut self, skill: Skill) -> Result<u8, String> {
+        if self.points() == 0 {
+            return Err(tr!("profile.no_points"));
+        }
+        let rank = self.skills.rank_mut(skill);
+        if *rank >= MAX_RANK {
+            return Err(tr!("profile.max_rank", MAX_RANK));
+        }
+        *rank += 1;
+        Ok(*rank)
+    }
+
+    // Record a finished mission and its XP
+    pub fn finish_mission(&mut self, xp: u32) -> Vec<String> {
+        let before = self.level();
+        self.xp += xp;
+        self.missions += 1;
+        let mut lines = vec![tr!("profile.earned", self.handle, xp, self.xp, self.level())];
+        if self.level() > before {
+            lines.push(tr!("profile.level_up", self.points(), self.handle));
+        }
+        lines
+    }
+
+    // Profile screen
+    pub fn summary_lines(&self) -> Vec<String> {
+        let mut lines = vec![tr!("profile.summary", self.handle, self.level(), self.xp, self.missions, self.points())];
+        for skill in SKILLS {
+            lines.push(format!("  [{}/{}] {}", self.skills.rank(skill), MAX_RANK, skill.describe()));
+        }
+        lines.push(format!(
+            "  {}",
+            tr!("profile.achievements", self.achievements.len(), achievements::ACHIEVEMENTS.len(), self.handle)
+        ));
+        lines
+    }
+}
@@ -0,0 +1,266 @@
+/*
+DATA TYPES USED:
+
+1. MissionScript    -> Struct: a compiled Rhai mission script and the state it keeps between hooks
+2. Hook             -> Enum: TurnStart, Hack, Guess, the moments a script can react to
+3. HOOKS            -> [(&str, usize); 3], hook function names and how many arguments each takes
+4. engine           -> rhai::Engine, the script interpreter (limited, with print() captured)
+5. ast              -> rhai::AST, the compiled script
+6. this             -> Dynamic (map), #{ player, system, state } bound to `this` inside a hook
+7. Map / Array      -> rhai::Map / rhai::Array, script-side objects and lists
+8. messages         -> Rc<RefCell<Vec<String>>>, text the script printed during a call
+9. state            -> RefCell<Map>, the script's own variables, kept from hook to hook
+10. node            -> Map, #{ row, col, difficulty, state, honeypot } for on_hack
+11. feedback        -> Map, #{ lock, opened, correct, misplaced, text } for on_guess
+12. tr!             -> macro (from i18n), message text in the chosen language
+*/
+
+use std::cell::RefCell; // Script state and printed messages
+use std::fs;            // Read the script file
+use std::path::Path;    // Script location
+use std::rc::Rc;        // Messages shared with the print callback
+
+use rhai::{Array, CallFnOptions, Dynamic, Engine, Map, Scope, AST};
+
+use crate::energy::OVERCHARGE_CAP; // Energy ceiling
+use crate::i18n::tr; // Translated text
+use crate::player::Player; // Operator stats
+use crate::security::firewall::{FirewallNode, NodeState}; // Firewall nodes
+use crate::security::SecuritySystem; // Security system
+
+// Hook functions a script may define, with their argument counts
+pub const HOOKS: [(&str, usize); 3] = [("on_turn_start", 0), ("on_hack", 2), ("on_guess", 2)];
+
+// Work one hook call may do before it is stopped (guards against endless loops)
+const MAX_OPERATIONS: u64 = 200_000;
+
+// Enum for the moments a script can react to
+pub enum Hook {
+    TurnStart,              // An operator's turn begins
+    Hack(Map, bool),        // A node hack was attempted: the node and whether it worked
+    Guess(String, Map),     // A vault lock was attempted: the input and the lock's answer
+}
+
+// Struct holding a loaded mission script
+pub struct MissionScript {
+    pub path: String,                    // File it was loaded from
+    pub intro: Vec<String>,              // Printed by the script's top-level code
+    engine: Engine,                      // Interpreter
+    ast: AST,                            // Compiled script
+    messages: Rc<RefCell<Vec<String>>>,  // Printed during the current call
+    state: RefCell<Map>,                 // `this.state`, kept between hooks
+}
+
+impl MissionScript {
+    // Compile a script, run its top-level code and check its hook names
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let source = fs::read_to_string(path).map_err(|e| tr!("script.read", path.display(), e))?;
+        let messages = Rc::new(RefCell::new(Vec::new()));
+
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+        engine.set_max_call_levels(32);
+        let printed = Rc::clone(&messages);
+        engine.on_print(move |s| printed.borrow_mut().push(s.to_string()));
+        let debugged = Rc::clone(&messages);
+        engine.on_debug(move |s, _, _| debugged.borrow_mut().push(s.to_string()));
+
+        let ast = engine
+            .compile(&source)
+            .map_err(|e| tr!("script.compile", path.display(), e))?;
+
+        // A misspelt hook would silently never run
+        for f in ast.iter_functions() {
+            if !f.name.starts_with("on_") {
+                continue;
+            }
+            match HOOKS.iter().find(|(name, _)| *name == f.name) {
+                Some((_, args)) if *args == f.params.len() => {}
+                Some((name, args)) => return Err(tr!("script.hook_args", name, args)),
+                None => {
+                    let names: Vec<&str> = HOOKS.iter().map(|(name, _)| *name).collect();
+                    return Err(tr!("script.unknown_hook", f.name, names.join(", ")));
+                }
+            }
+        }
+
+        engine
+            .run_ast_with_scope(&mut Scope::new(), &ast)
+            .map_err(|e| tr!("script.failed", path.display(), e))?;
+        let intro = messages.borrow_mut().drain(..).collect();
+
+        Ok(MissionScript {
+            path: path.display().to_string(),
+            intro,
+            engine,
+            ast,
+            messages,
+            state: RefCell::new(Map::new()),
+        })
+    }
+
+    // Copy of the script's own variables (practice-mode snapshots)
+    pub fn state(&self) -> Map {
+        self.state.borrow().clone()
+    }
+
+    // Put back variables saved with `state` (practice-mode undo)
+    pub fn restore(&self, state: Map) {
+        *self.state.borrow_mut() = state;
+    }
+
+    // Hooks the script defines
+    pub fn hooks(&self) -> Vec<&'static str> {
+        HOOKS
+            .iter()
+            .filter(|(name, _)| self.ast.iter_functions().any(|f| f.name == *name))
+            .map(|(name, _)| *name)
+            .collect()
+    }
+
+    // Run a hook with `this` = #{ player, system, state }, then copy writable fields back
+    pub fn run(&self, hook: Hook, player: &mut Player, system: &mut SecuritySystem) -> Vec<String> {
+        let (name, args): (&str, Vec<Dynamic>) = match hook {
+            Hook::TurnStart => ("on_turn_start", Vec::new()),
+            Hook::Hack(node, success) => ("on_hack", vec![node.into(), success.into()]),
+            Hook::Guess(guess, feedback) => ("on_guess", vec![guess.into(), feedback.into()]),
+        };
+        if !self.hooks().contains(&name) {
+            return Vec::new();
+        }
+
+        let mut this = Map::new();
+        this.insert("player".into(), player_map(player).into());
+        this.insert("system".into(), system_map(system).into());
+        this.insert("state".into(), self.state.borrow().clone().into());
+        let mut this: Dynamic = this.into();
+
+        let options = CallFnOptions::new().eval_ast(false).bind_this_ptr(&mut this);
+        let result = self
+            .engine
+            .call_fn_with_options::<Dynamic>(options, &mut Scope::new(), &self.ast, name, args);
+
+        let mut lines: Vec<String> = self.messages.borrow_mut().drain(..).collect();
+        if let Err(e) = result {
+            lines.push(tr!("script.error", name, e));
+            return lines; // A failed hook changes nothing
+        }
+        let this = this.cast::<Map>();
+        if let Some(p) = this.get("player").and_then(|d| d.read_lock::<Map>()) {
+            write_player(&p, player);
+        }
+        if let Some(s) = this.get("system").and_then(|d| d.read_lock::<Map>()) {
+            write_system(&s, system);
+        }
+        if let Some(state) = this.get("state").and_then(|d| d.read_lock::<Map>()) {
+            *self.state.borrow_mut() = state.clone();
+        }
+        lines
+    }
+}
+
+// Lower-case name of a node state
+fn state_name(state: NodeState) -> &'static str {
+    match state {
+        NodeState::Secure => "secure",
+        NodeState::Vulnerable => "vulnerable",
+        NodeState::Compromised => "compromised",
+        NodeState::Exploded => "exploded",
+    }
+}
+
+// Script view of a node (also the `node` argument of on_hack)
+pub fn node_map(node: &FirewallNode) -> Map {
+    let mut m = Map::new();
+    m.insert("row".into(), (node.coord.0 as i64).into());
+    m.insert("col".into(), (node.coord.1 as i64).into());
+    m.insert("difficulty".into(), (node.difficulty as i64).into());
+    m.insert("state".into(), state_name(node.state).into());
+    m.insert("honeypot".into(), node.honeypot.into());
+    m.insert("owner".into(), node.owner.map_or(-1, |o| o as i64).into());
+    m
+}
+
+// Script view of an operator
+fn player_map(player: &Player) -> Map {
+    let mut m = Map::new();
+    m.insert("id".into(), (player.id as i64).into());
+    m.insert("name".into(), player.name.clone().into());
+    m.insert("class".into(), player.class.map_or("", |c| c.name()).into());
+    m.insert("energy".into(), (player.energy as i64).into());
+    m.insert("rated_energy".into(), (player.rated_energy as i64).into());
+    m.insert("hacks_done".into(), (player.hacks_done as i64).into());
+    m.insert("brute_force_used".into(), (player.brute_force_used as i64).into());
+    let items: Array = player.inventory.iter().map(|i| i.clone().into()).collect();
+    m.insert("inventory".into(), items.into());
+    m
+}
+
+// Script view of the security system
+fn system_map(system: &SecuritySystem) -> Map {
+    let mut m = Map::new();
+    m.insert("lock_timer".into(), (system.lock_timer as i64).into());
+    m.insert("alert_level".into(), (system.alert_level as i64).into());
+    m.insert("detection".into(), system.detection().tag().into());
+    m.insert("difficulty".into(), format!("{:?}", system.difficulty).to_lowercase().into());
+    m.insert("clock".into(), (system.clock as i64).into());
+    m.insert("code_scans".into(), (system.code_scans as i64).into());
+    m.insert("grid_scans".into(), (system.grid_scans as i64).into());
+    m.insert("honeypot_active".into(), system.honeypot_active.into());
+    m.insert("compromised".into(), (system.compromised_nodes() as i64).into());
+    let code: String = system.code_digits.iter().map(|d| d.to_string()).collect();
+    m.insert("code".into(), code.into());
+    let firewall: Array = system.firewall.iter().map(|n| node_map(n).into()).collect();
+    m.insert("firewall".into(), firewall.into());
+    m
+}
+
+// Whole number field of a script map
+fn int(m: &Map, key: &str) -> Option<i64> {
+    m.get(key).and_then(|d| d.as_int().ok())
+}
+
+// Copy back what a script may change on an operator: energy, rated energy and inventory
+fn write_player(m: &Map, player: &mut Player) {
+    if let Some(n) = int(m, "rated_energy") {
+        player.rated_energy = n.clamp(1, OVERCHARGE_CAP as i64) as i32;
+    }
+    if let Some(n) = int(m, "energy") {
+        player.energy = n.clamp(0, OVERCHARGE_CAP as i64) as i32;
+    }
+    if let Some(items) = m.get("inventory").and_then(|d| d.read_lock::<Array>()) {
+        player.inventory = items.iter().filter_map(|i| i.clone().into_string().ok()).collect();
+    }
+}
+
+// Copy back what a script may change on the security system
+fn write_system(m: &Map, system: &mut SecuritySystem) {
+    if let Some(n) = int(m, "lock_timer") {
+        system.lock_timer = n.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+    }
+    if let Some(n) = int(m, "alert_level") {
+        system.alert_level = n.clamp(0, u32::MAX as i64) as u32;
+    }
+    if let Some(b) = m.get("honeypot_active").and_then(|d| d.as_bool().ok()) {
+        system.honeypot_active = b;
+    }
+    // Nodes: difficulty and honeypot always, state only between secure and vulnerable
+    let Some(nodes) = m.get("firewall").and_then(|d| d.read_lock::<Array>()) else {
+        return;
+    };
+    for (node, d) in system.firewall.iter_mut().zip(nodes.iter()) {
+        let Some(n) = d.read_lock::<Map>() else { continue };
+        if let Some(v) = int(&n, "difficulty") {
+            node.difficulty = v.clamp(1, 99) as u8;
+        }
+        if let Some(b) = n.get("honeypot").and_then(|d| d.as_bool().ok()) {
+            node.honeypot = b;
+        }
+        let open = matches!(node.state, NodeState::Secure | NodeState::Vulnerable);
+        match n.get("state").and_then(|d| d.clone().into_string().ok()).as_deref() {
+            Some("secure") if open => node.state = NodeState::Secure,
+            Some("vulnerable") if open => node.state = NodeState::Vulnerable,
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,85 @@
+/*
+DATA TYPES USED:
+
+1. CipherLock       -> Struct: a word hidden by a one-byte XOR key
+2. plain            -> String, the hidden word (uppercase)
+3. key              -> u8, byte XORed into every letter
+4. bytes            -> Vec<u8>, encrypted letters shown to the operator
+5. right            -> usize, letters of an attempt in the right place
+6. tr!              -> macro (from i18n), message text in the chosen language
+*/
+
+use rand::seq::SliceRandom; // Pick a random word
+use rand::Rng;              // Random number generator
+
+use crate::energy; // Cost table
+use crate::i18n::tr; // Translated text
+use super::password::WORDS; // Shared word list
+use super::puzzle::{Attempt, PuzzleContext, VaultPuzzle}; // Puzzle trait
+
+// Struct for the XOR cipher lock
+#[derive(Debug, Clone)]
+pub struct CipherLock {
+    plain: String, // Hidden word
+    key: u8,       // One-byte XOR key
+}
+
+impl CipherLock {
+    // Random word under a random non-zero key
+    pub fn new(rng: &mut impl Rng) -> Self {
+        CipherLock {
+            plain: WORDS.choose(rng).copied().unwrap_or("VAULT").to_string(),
+            key: rng.gen_range(1..=255),
+        }
+    }
+
+    // Encrypted letters, as shown on the lock
+    fn ciphertext(&self) -> String {
+        let bytes: Vec<String> = self.plain.bytes().map(|b| format!("{:02x}", b ^ self.key)).collect();
+        bytes.join(" ")
+    }
+}
+
+impl Default for CipherLock {
+    fn default() -> Self {
+        Self::new(&mut rand::thread_rng())
+    }
+}
+
+impl VaultPuzzle for CipherLock {
+    fn name(&self) -> &'static str {
+        "cipher lock"
+    }
+
+    fn label(&self) -> String {
+        tr!("lock.cipher")
+    }
+
+    fn describe(&self) -> String {
+        tr!("lock.cipher_describe")
+    }
+
+    fn cost(&self) -> i32 {
+        energy::CIPHER_GUESS
+    }
+
+    fn attempt(&self, input: &str, _ctx: &PuzzleContext) -> Result<Attempt, String> {
+        let guess = input.trim().to_uppercase();
+        if guess.len() != self.plain.len() || !guess.bytes().all(|b| b.is_ascii_uppercase()) {
+            return Err(tr!("lock.cipher_length", self.plain.len()));
+        }
+        if guess == self.plain {
+            return Ok(Attempt::solved(&tr!("lock.cipher_solved")));
+        }
+        let right = guess.bytes().zip(self.plain.bytes()).filter(|(g, p)| g == p).count();
+        Ok(Attempt::failed(vec![tr!("lock.cipher_feedback", right)]))
+    }
+
+    // Ciphertext and a known-plaintext crib
+    fn reveal_lines(&self, _ctx: &PuzzleContext) -> Vec<String> {
+        vec![
+            tr!("lock.cipher_text", self.ciphertext()),
+            tr!("lock.cipher_crib", &self.plain[..1], format!("{:02x}", self.plain.as_bytes()[0])),
+        ]
+    }
+}
@@ -7,29 +7,97 @@ DATA TYPES USED:
 4. misplaced : usize    -> Counter for digits correct but in wrong position
 5. code_counts : HashMap<u8, usize>  -> Counts of unmatched digits in secret code
 6. guess_counts : HashMap<u8, usize> -> Counts of unmatched digits in user guess
-7. input : String       -> Raw input string from user
-8. raw : &str           -> Trimmed string slice of input
-9. digit : &u8          -> Key from HashMap iteration
-10. gcount : &usize     -> Value from HashMap iteration
-11. i : usize           -> Loop index
-12. ch : char           -> Character from input string
+7. raw : &str           -> Trimmed string slice of input (parse_code)
+8. digit : &u8          -> Key from HashMap iteration
+9. gcount : &usize      -> Value from HashMap iteration
+10. i : usize           -> Loop index
+11. ch : char           -> Character from input string
+12. CodeClue            -> Enum: DigitAt, DigitSum, DigitParity, DigitAbove, DigitBelow, HasRepeat, DigitProduct
+13. tr!                 -> macro (from i18n), message text in the chosen language
 */
 
-// Read input
-use crate::utils::read_input;
 // Import HashMap type
 use std::collections::HashMap;
 // Import random number generator trait
 use rand::Rng;
+// Import translated text
+use crate::i18n::tr;
+
+// Enum describing one piece of known information about the vault code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodeClue {
+    DigitAt(usize, u8),      // Digit at position (0-based) has this value
+    DigitSum(u8),            // Sum of all 4 digits
+    DigitParity(usize, bool), // Digit at position is even (true) or odd (false)
+    DigitAbove(usize, u8),   // Digit at position is greater than the value
+    DigitBelow(usize, u8),   // Digit at position is less than the value
+    HasRepeat(bool),         // Whether any digit appears more than once
+    DigitProduct(u16),       // Product of all 4 digits
+}
+
+impl CodeClue {
+    // Check whether a candidate code agrees with this clue
+    pub fn matches(&self, code: &[u8; 4]) -> bool {
+        match *self {
+            CodeClue::DigitAt(pos, value) => code[pos] == value,
+            CodeClue::DigitSum(sum) => code.iter().sum::<u8>() == sum,
+            CodeClue::DigitParity(pos, even) => code[pos].is_multiple_of(2) == even,
+            CodeClue::DigitAbove(pos, value) => code[pos] > value,
+            CodeClue::DigitBelow(pos, value) => code[pos] < value,
+            CodeClue::HasRepeat(repeat) => has_repeat(code) == repeat,
+            CodeClue::DigitProduct(product) => digit_product(code) == product,
+        }
+    }
+
+    // Position the clue talks about, if it concerns a single digit
+    pub fn position(&self) -> Option<usize> {
+        match *self {
+            CodeClue::DigitAt(pos, _)
+            | CodeClue::DigitParity(pos, _)
+            | CodeClue::DigitAbove(pos, _)
+            | CodeClue::DigitBelow(pos, _) => Some(pos),
+            _ => None,
+        }
+    }
+
+    // Short description used in the status screen
+    pub fn describe(&self) -> String {
+        match *self {
+            CodeClue::DigitAt(pos, value) => tr!("clue.digit_at", pos + 1, value),
+            CodeClue::DigitSum(sum) => tr!("clue.sum", sum),
+            CodeClue::DigitParity(pos, true) => tr!("clue.even", pos + 1),
+            CodeClue::DigitParity(pos, false) => tr!("clue.odd", pos + 1),
+            CodeClue::DigitAbove(pos, value) => tr!("clue.above", pos + 1, value),
+            CodeClue::DigitBelow(pos, value) => tr!("clue.below", pos + 1, value),
+            CodeClue::HasRepeat(true) => tr!("clue.repeat"),
+            CodeClue::HasRepeat(false) => tr!("clue.no_repeat"),
+            CodeClue::DigitProduct(product) => tr!("clue.product", product),
+        }
+    }
+}
+
+// Check whether any digit appears more than once in a code
+pub fn has_repeat(code: &[u8; 4]) -> bool {
+    (0..4).any(|i| (i + 1..4).any(|j| code[i] == code[j]))
+}
+
+// Multiply all digits of a code together
+pub fn digit_product(code: &[u8; 4]) -> u16 {
+    code.iter().map(|&d| d as u16).product()
+}
 
 // Function to generate a random 4-digit code
 pub fn generate_code() -> [u8; 4] {
-    let mut rng = rand::thread_rng(); // Thread-local random number generator
+    generate_code_with(&mut rand::thread_rng()) // Thread-local random number generator
+}
+
+// Generate a code from a given generator (seeded for the daily challenge)
+pub fn generate_code_with(rng: &mut impl Rng) -> [u8; 4] {
     let mut arr = [0; 4]; // 4-element array of u8, initialized to 0
 
-    for i in 0..4 { // Loop through indices 0 to 3
-        arr[i] = rng.gen_range(0..10); 
-        // Assign a random number 0-9 to arr[i]
+    for digit in arr.iter_mut() { // Loop through all 4 positions
+        *digit = rng.gen_range(0..10);
+        // Assign a random number 0-9 to this position
     }
     arr // Return the array
 }
@@ -64,15 +132,12 @@ pub fn mastermind_feedback(code: &[u8; 4], guess: &[u8; 4]) -> (usize, usize) {
     (correct, misplaced) // Return feedback
 }
 
-// Function to read a 4-digit guess from user
-pub fn read_code_guess() -> Option<[u8; 4]> {
-    println!("Enter 4-digit guess:"); // Prompt user
-    let input = read_input().ok()?; // Read input -> Option<String>
+// Function to parse a 4-digit code such as "0427"
+pub fn parse_code(input: &str) -> Option<[u8; 4]> {
     let raw = input.trim(); // Trim whitespace -> &str
 
     // Validate input length and ensure all characters are digits
     if raw.len() != 4 || !raw.chars().all(|c| c.is_ascii_digit()) {
-        println!("Invalid format.");
         return None;
     }
 
@@ -0,0 +1,220 @@
+/*
+DATA TYPES USED:
+
+1. DefenderKind         -> Enum: Human, Ai
+2. Defender             -> Struct: sysadmin name, controller and defense budget
+3. DefenseMove          -> Enum: Secure, Trap, Harden, Rotate, End
+4. budget, income       -> u32, defense points available / gained each turn
+5. cost                 -> u32, price of a defense move
+6. r, c, idx            -> usize, node coordinates and index
+7. pos                  -> usize, code position (0-based)
+8. words                -> Vec<&str>, move name followed by arguments
+9. leaders              -> Vec<(usize, usize)>, (owned nodes, owner id) per attacker
+10. Result<u32, String> -> points spent, or why the move was refused
+11. tr!                 -> macro (from i18n), message text in the chosen language
+*/
+
+use std::str::FromStr; // Parse defense moves typed by a human sysadmin
+
+use rand::Rng; // Random number generator
+
+use crate::i18n::tr; // Translated text
+use super::firewall::{FirewallNode, NodeState}; // Firewall components
+use super::{Difficulty, SecuritySystem};         // Shared security system
+
+// Costs of each defense move, in budget points
+pub const SECURE_COST: u32 = 5; // Take a compromised node back
+pub const TRAP_COST: u32 = 3;   // Turn an open node into a honeypot
+pub const HARDEN_COST: u32 = 2; // Raise a node's difficulty
+pub const ROTATE_COST: u32 = 6; // Re-key one digit of the vault code
+
+// Difficulty added by one harden move, and the ceiling
+const HARDEN_STEP: u8 = 15;
+const MAX_NODE_DIFFICULTY: u8 = 99;
+
+// Most budget a defender can bank between turns
+const BUDGET_CAP: u32 = 12;
+
+// Enum for who controls the sysadmin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefenderKind {
+    Human, // Typed moves (hot-seat or network)
+    Ai,    // Automated defense
+}
+
+// Struct for the sysadmin role
+#[derive(Debug, Clone)]
+pub struct Defender {
+    pub name: String,       // Sysadmin handle
+    pub kind: DefenderKind, // Human or AI
+    pub budget: u32,        // Points available to spend
+}
+
+// Enum for moves the sysadmin can make
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefenseMove {
+    Secure(usize, usize), // Re-secure a compromised node
+    Trap(usize, usize),   // Plant a honeypot on an open node
+    Harden(usize, usize), // Boost a node's difficulty
+    Rotate(usize),        // Change the digit at a code position (0-based)
+    End,                  // Finish the defense turn, banking the rest
+}
+
+// Convert "secure 2 3", "rotate 1" etc. into DefenseMove (positions typed 1-4)
+impl FromStr for DefenseMove {
+    type Err = (); // Unit type () used as error
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.trim().to_lowercase();
+        let words: Vec<&str> = lower.split_whitespace().collect();
+        let coord = |r: &str, c: &str| -> Result<(usize, usize), ()> {
+            Ok((r.parse().map_err(|_| ())?, c.parse().map_err(|_| ())?))
+        };
+
+        match words.as_slice() {
+            ["secure", r, c] => coord(r, c).map(|(r, c)| DefenseMove::Secure(r, c)),
+            ["trap" | "honeypot", r, c] => coord(r, c).map(|(r, c)| DefenseMove::Trap(r, c)),
+            ["harden", r, c] => coord(r, c).map(|(r, c)| DefenseMove::Harden(r, c)),
+            ["rotate", p] => match p.parse::<usize>() {
+                Ok(p) if (1..=4).contains(&p) => Ok(DefenseMove::Rotate(p - 1)),
+                _ => Err(()),
+            },
+            ["end" | "done" | "pass"] => Ok(DefenseMove::End),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Defender {
+    // Constructor for a sysadmin with an empty budget
+    pub fn new(name: &str, kind: DefenderKind) -> Self {
+        Defender {
+            name: name.to_string(),
+            kind,
+            budget: 0,
+        }
+    }
+
+    // Add this turn's income, keeping the bank under the cap
+    pub fn collect_income(&mut self, difficulty: Difficulty) {
+        let income = match difficulty {
+            Difficulty::Easy => 2,
+            Difficulty::Normal => 3,
+            Difficulty::Hard => 4,
+        };
+        self.budget = (self.budget + income).min(BUDGET_CAP);
+    }
+}
+
+// Price of a move
+pub fn move_cost(mv: DefenseMove) -> u32 {
+    match mv {
+        DefenseMove::Secure(..) => SECURE_COST,
+        DefenseMove::Trap(..) => TRAP_COST,
+        DefenseMove::Harden(..) => HARDEN_COST,
+        DefenseMove::Rotate(_) => ROTATE_COST,
+        DefenseMove::End => 0,
+    }
+}
+
+// Node that can still be attacked (not compromised or exploded)
+fn is_open(node: &FirewallNode) -> bool {
+    matches!(node.state, NodeState::Secure | NodeState::Vulnerable)
+}
+
+// Apply a move to the system; returns points spent or why it was refused
+pub fn apply_move(system: &mut SecuritySystem, mv: DefenseMove, budget: u32, cols: usize) -> Result<u32, String> {
+    let cost = move_cost(mv);
+    if cost > budget {
+        return Err(tr!("def.no_budget", cost, budget));
+    }
+
+    // Look up the target node for moves that have one
+    let node_index = |r: usize, c: usize| -> Result<usize, String> {
+        if c >= cols || r * cols + c >= system.firewall.len() {
+            return Err(tr!("game.out_of_range"));
+        }
+        Ok(r * cols + c)
+    };
+
+    match mv {
+        DefenseMove::Secure(r, c) => {
+            let idx = node_index(r, c)?;
+            let node = &mut system.firewall[idx];
+            if node.state != NodeState::Compromised {
+                return Err(tr!("def.not_compromised", r, c));
+            }
+            node.state = NodeState::Secure;
+            node.owner = None;
+        }
+        DefenseMove::Trap(r, c) => {
+            let idx = node_index(r, c)?;
+            let node = &mut system.firewall[idx];
+            if !is_open(node) || node.honeypot {
+                return Err(tr!("def.no_trap", r, c));
+            }
+            node.honeypot = true;
+            node.flagged = false;
+        }
+        DefenseMove::Harden(r, c) => {
+            let idx = node_index(r, c)?;
+            let node = &mut system.firewall[idx];
+            if !is_open(node) || node.difficulty >= MAX_NODE_DIFFICULTY {
+                return Err(tr!("def.no_harden", r, c));
+            }
+            node.difficulty = node.difficulty.saturating_add(HARDEN_STEP).min(MAX_NODE_DIFFICULTY);
+        }
+        DefenseMove::Rotate(pos) => {
+            system.rekey(&[pos]);
+        }
+        DefenseMove::End => {}
+    }
+    Ok(cost)
+}
+
+// Pick at most one move for the automated sysadmin (None = save the budget)
+pub fn ai_move(system: &SecuritySystem, budget: u32, cracked: bool, owners: &[usize]) -> Option<DefenseMove> {
+    let mut rng = rand::thread_rng();
+    let total = system.firewall.len();
+    let needed = (total * 30).div_ceil(100); // Nodes an attacker needs to win
+
+    // A cracked code is the biggest threat: re-key it
+    if cracked && budget >= ROTATE_COST {
+        return Some(DefenseMove::Rotate(rng.gen_range(0..4)));
+    }
+
+    // Take a node back from the attacker closest to the objective
+    let mut leaders: Vec<(usize, usize)> = owners.iter().map(|&o| (system.owned_nodes(o), o)).collect();
+    leaders.sort();
+    if let Some(&(owned, owner)) = leaders.last()
+        && owned + 2 >= needed
+        && budget >= SECURE_COST
+    {
+        let node = system
+            .firewall
+            .iter()
+            .filter(|n| n.owner == Some(owner) && n.state == NodeState::Compromised)
+            .max_by_key(|n| n.difficulty)?; // Hardest node to hack again
+        return Some(DefenseMove::Secure(node.coord.0, node.coord.1));
+    }
+
+    // Otherwise bait or harden the nodes attackers are most likely to try
+    let open: Vec<&FirewallNode> = system
+        .firewall
+        .iter()
+        .filter(|n| is_open(n) && !n.honeypot)
+        .collect();
+    let target = open
+        .iter()
+        .filter(|n| n.state == NodeState::Vulnerable)
+        .min_by_key(|n| n.difficulty)
+        .or_else(|| open.iter().min_by_key(|n| n.difficulty))?;
+
+    if budget >= TRAP_COST + HARDEN_COST && rng.gen_bool(0.5) {
+        Some(DefenseMove::Trap(target.coord.0, target.coord.1))
+    } else if budget >= HARDEN_COST + SECURE_COST {
+        Some(DefenseMove::Harden(target.coord.0, target.coord.1))
+    } else {
+        None
+    }
+}
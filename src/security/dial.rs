@@ -0,0 +1,69 @@
+/*
+DATA TYPES USED:
+
+1. DialLock         -> Struct: a number found by "higher/lower" feedback
+2. max              -> u32, largest value the dial can hold
+3. secret, guess    -> u32, dial setting and attempted value
+4. tr!              -> macro (from i18n), message text in the chosen language
+*/
+
+use rand::Rng; // Random number generator
+
+use crate::energy; // Cost table
+use crate::i18n::tr; // Translated text
+use super::puzzle::{Attempt, PuzzleContext, VaultPuzzle}; // Puzzle trait
+use super::Difficulty;                                   // Difficulty enum
+
+// Struct for the higher/lower dial lock
+#[derive(Debug, Clone, Copy)]
+pub struct DialLock {
+    max: u32,    // Dial runs from 0 to max
+    secret: u32, // Setting that opens the lock
+}
+
+impl DialLock {
+    // Random setting on a dial sized by difficulty
+    pub fn new(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let max = match difficulty {
+            Difficulty::Easy => 100,
+            Difficulty::Normal => 250,
+            Difficulty::Hard => 500,
+        };
+        DialLock {
+            max,
+            secret: rng.gen_range(0..=max),
+        }
+    }
+}
+
+impl VaultPuzzle for DialLock {
+    fn name(&self) -> &'static str {
+        "dial lock"
+    }
+
+    fn label(&self) -> String {
+        tr!("lock.dial")
+    }
+
+    fn describe(&self) -> String {
+        tr!("lock.dial_describe", self.max)
+    }
+
+    fn cost(&self) -> i32 {
+        energy::DIAL_TURN
+    }
+
+    fn attempt(&self, input: &str, _ctx: &PuzzleContext) -> Result<Attempt, String> {
+        let guess = input
+            .trim()
+            .parse::<u32>()
+            .ok()
+            .filter(|n| *n <= self.max)
+            .ok_or_else(|| tr!("lock.dial_range", self.max))?;
+        if guess == self.secret {
+            return Ok(Attempt::solved(&tr!("lock.dial_solved")));
+        }
+        let hint = if guess < self.secret { tr!("lock.dial_higher") } else { tr!("lock.dial_lower") };
+        Ok(Attempt::failed(vec![tr!("lock.dial_feedback", guess, hint)]))
+    }
+}
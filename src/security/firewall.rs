@@ -14,9 +14,33 @@ DATA TYPES USED:
 11. base                 -> u8, base chance of hack success
 12. energy_bonus          -> u8, bonus based on player energy
 13. roll                  -> u32, random number roll to determine hack success
-14. threshold             -> u8, calculated success threshold
+14. threshold             -> i32, calculated success threshold (0-100)
 15. explode_roll          -> u32, random roll for honeypot explosion
 16. fw                    -> &[FirewallNode], slice of nodes to display
+17. honeypot              -> bool, whether the node is a hidden trap
+18. flagged               -> bool, whether a scan has exposed the trap
+19. trap_chance           -> u32, percentage chance a node is a honeypot
+20. owner                 -> Option<usize>, seat id of the operator who hacked the node
+21. show_owners           -> bool, print owner numbers instead of 'C'
+22. log                   -> &mut Vec<String>, messages for the acting operator
+23. lines, row            -> Vec<String> / String, rendered map text
+24. reveal_traps          -> bool, show every honeypot (sysadmin view)
+25. state_bonus           -> i32, hack chance change from the operator's energy state
+26. skill_bonus           -> i32, hack chance added by the exploit dev skill
+27. class_bonus           -> i32, hack chance added by the Infiltrator class
+28. layout                -> &[&str], hand-drawn grid, one string per row (v/s/h per node)
+29. style                 -> Style, color theme and screen-reader option for the map
+30. role                  -> Role, what a map symbol stands for (picks its color)
+31. tr!                   -> macro (from i18n), message text in the chosen language
+32. kind                  -> Option<usize>, content-pack node type (index into Content::nodes)
+33. profile               -> &DifficultyProfile (from content), generation numbers for the difficulty
+34. siphon                -> i32, energy drawn from a hacked node
+35. neighbours            -> Vec<(usize, usize)>, nodes touching a node horizontally or vertically
+36. HackOdds              -> Struct: cost, success threshold and explosion rolls of a hack right now
+37. explode_above         -> (u32, u32), explosion roll a failed hack must beat (honeypot, plain node)
+38. cell                  -> String, one node of the odds overlay ("62/ 9/ 5")
+39. held                  -> &dyn Fn(&FirewallNode) -> bool, nodes that give an operator a foothold
+40. reach                 -> bool / &dyn Fn(&FirewallNode) -> bool, whether the operator can hack a node
 */
 
 // Import random number generator
@@ -24,7 +48,11 @@ use rand::Rng;
 
 // Import clamp function and Player struct
 use crate::utils::clamp_generic;
-use crate::player::Player;
+use crate::player::{self, OperatorClass, Player};
+use crate::energy::{self, EnergyState};
+use crate::theme::{Role, Style};
+use crate::i18n::tr;
+use crate::content;
 use super::Difficulty;
 
 // Enum to represent the state of a firewall node
@@ -42,6 +70,10 @@ pub struct FirewallNode {
     pub coord: (usize, usize), // Coordinates (row, column)
     pub state: NodeState,      // Current state
     pub difficulty: u8,        // Difficulty value for hacking
+    pub honeypot: bool,        // Hidden trap: failed hacks explode far more often
+    pub flagged: bool,         // Honeypot exposed by a grid scan
+    pub owner: Option<usize>,  // Seat id of the operator holding the node
+    pub kind: Option<usize>,   // Content-pack node type, if any
 }
 
 // Function to create the firewall grid
@@ -49,62 +81,194 @@ pub fn create_firewall_grid(
     rows: usize,           // Number of rows
     cols: usize,           // Number of columns
     difficulty: Difficulty // Game difficulty
+) -> Vec<FirewallNode> {
+    create_firewall_grid_with(&mut rand::thread_rng(), rows, cols, difficulty)
+}
+
+// Create the firewall grid from a given generator (seeded for the daily challenge)
+pub fn create_firewall_grid_with(
+    rng: &mut impl Rng,    // Random number generator
+    rows: usize,           // Number of rows
+    cols: usize,           // Number of columns
+    difficulty: Difficulty // Game difficulty
 ) -> Vec<FirewallNode> {
     let mut nodes = Vec::with_capacity(rows * cols); // Vector to hold all nodes
-    let mut rng = rand::thread_rng(); // Random number generator
+    let content = content::get();
+    let profile = content.profile(difficulty); // Numbers for this difficulty (built in or from a pack)
 
     for r in 0..rows {
         for c in 0..cols {
             let chance = rng.gen_range(0..100); // Random chance for node state
 
             // Decide node state based on difficulty and chance
-            let state = match difficulty {
-                Difficulty::Easy => if chance < 45 { NodeState::Vulnerable } else { NodeState::Secure },
-                Difficulty::Normal => if chance < 30 { NodeState::Vulnerable } else { NodeState::Secure },
-                Difficulty::Hard => if chance < 15 { NodeState::Vulnerable } else { NodeState::Secure },
-            };
+            let state = if chance < profile.vulnerable { NodeState::Vulnerable } else { NodeState::Secure };
 
             // Assign difficulty value based on game difficulty
-            let node_diff = match difficulty {
-                Difficulty::Easy => rng.gen_range(5..30),
-                Difficulty::Normal => rng.gen_range(20..60),
-                Difficulty::Hard => rng.gen_range(40..95),
-            } as u8;
+            let node_diff = rng.gen_range(profile.node_min..=profile.node_max) as u8;
+
+            // Chance for the node to be a honeypot
+            let trap_chance = profile.honeypots;
 
             // Add node to vector
-            nodes.push(FirewallNode {
+            let mut node = FirewallNode {
                 coord: (r, c),
                 state,
                 difficulty: node_diff,
-            });
+                honeypot: rng.gen_range(0..100) < trap_chance,
+                flagged: false,
+                owner: None,
+                kind: None,
+            };
+
+            // Content packs may turn the node into one of their node types
+            if !content.nodes.is_empty() {
+                let mut roll = rng.gen_range(0..100);
+                for (i, t) in content.nodes.iter().enumerate() {
+                    if roll < t.share {
+                        node.state = if t.vulnerable { NodeState::Vulnerable } else { NodeState::Secure };
+                        node.difficulty = rng.gen_range(t.min..=t.max) as u8;
+                        node.honeypot = rng.gen_range(0..100) < t.honeypot;
+                        node.kind = Some(i);
+                        break;
+                    }
+                    roll -= t.share;
+                }
+            }
+            nodes.push(node);
         }
     }
 
     nodes // Return the vector of nodes
 }
 
+// Build a fixed grid from a layout: 'v' vulnerable, 's' secure, 'h' secure honeypot
+pub fn grid_from_layout(layout: &[&str]) -> Vec<FirewallNode> {
+    let mut nodes = Vec::new();
+    for (r, row) in layout.iter().enumerate() {
+        for (c, cell) in row.chars().enumerate() {
+            let (state, difficulty) = match cell {
+                'v' => (NodeState::Vulnerable, 10),
+                _ => (NodeState::Secure, 25),
+            };
+            nodes.push(FirewallNode {
+                coord: (r, c),
+                state,
+                difficulty,
+                honeypot: cell == 'h',
+                flagged: false,
+                owner: None,
+                kind: None,
+            });
+        }
+    }
+    nodes
+}
+
+// Nodes touching (r, c) horizontally or vertically: the firewall's adjacency
+pub fn neighbours(coord: (usize, usize), rows: usize, cols: usize) -> Vec<(usize, usize)> {
+    [(0, 1), (2, 1), (1, 0), (1, 2)]
+        .iter()
+        .filter_map(|&(dr, dc)| {
+            let r = (coord.0 + dr).checked_sub(1)?;
+            let c = (coord.1 + dc).checked_sub(1)?;
+            (r < rows && c < cols).then_some((r, c))
+        })
+        .collect()
+}
+
+// Whether a node sits on the edge of the grid, where anyone can break in
+pub fn on_edge(coord: (usize, usize), rows: usize, cols: usize) -> bool {
+    coord.0 == 0 || coord.1 == 0 || coord.0 + 1 == rows || coord.1 + 1 == cols
+}
+
+// Whether a node can be hacked: on the edge of the grid, or next to a node the operator holds
+pub fn in_reach(fw: &[FirewallNode], rows: usize, cols: usize, coord: (usize, usize), held: &dyn Fn(&FirewallNode) -> bool) -> bool {
+    on_edge(coord, rows, cols) || neighbours(coord, rows, cols).iter().any(|&(r, c)| held(&fw[r * cols + c]))
+}
+
+// Energy a node hack costs before skills: the node's price, less the Infiltrator discount
+pub fn hack_price(player: &Player, node_difficulty: u8) -> i32 {
+    let cost = energy::hack_cost(node_difficulty);
+    if player.is(OperatorClass::Infiltrator) { cost - player::INFILTRATOR_HACK_DISCOUNT } else { cost }
+}
+
+// Struct for what hacking a node would cost and risk right now
+#[derive(Debug, Clone, Copy)]
+pub struct HackOdds {
+    pub cost: i32,                 // Energy the attempt costs
+    pub threshold: i32,            // Success on a roll (0-99) below this, so also the success chance in %
+    pub explode_above: (u32, u32), // A failed attempt explodes the node on a roll above this (honeypot, plain)
+}
+
+impl HackOdds {
+    // Chance in % that a failed attempt blows the node
+    pub fn blast_chance(&self, honeypot: bool) -> u32 {
+        let above = if honeypot { self.explode_above.0 } else { self.explode_above.1 };
+        99u32.saturating_sub(above) // Rolls above `above` up to 99
+    }
+
+    // Chance in % that the attempt ends with the node exploded
+    pub fn explosion_risk(&self, honeypot: bool) -> f64 {
+        (100 - self.threshold) as f64 * self.blast_chance(honeypot) as f64 / 100.0
+    }
+}
+
+// Cost and odds of hacking a node, worked out on the energy left once the cost is paid
+pub fn hack_odds(player: &Player, node: &FirewallNode) -> HackOdds {
+    // Calculate energy cost to hack
+    let cost = player.cost(hack_price(player, node.difficulty));
+    let energy = player.energy - cost; // The roll happens after the energy is spent
+
+    // Base chance of success
+    let base = if node.state == NodeState::Vulnerable { 50 } else { 20 };
+
+    // Energy bonus improves chance
+    let energy_bonus = clamp_generic(energy, 0, 50) as u8;
+
+    // Drained operators fumble, overcharged ones push harder
+    let state_bonus = EnergyState::of(energy, player.rated_energy).hack_bonus();
+
+    // Trained exploit developers write better payloads
+    let skill_bonus = player.skills.hack_bonus();
+    let class_bonus = if player.is(OperatorClass::Infiltrator) { player::INFILTRATOR_HACK_BONUS } else { 0 };
+
+    let threshold = clamp_generic(
+        (base + (energy_bonus / 2) + (100 - node.difficulty) / 5) as i32 + state_bonus + skill_bonus + class_bonus,
+        0,
+        100,
+    ); // Success threshold
+
+    let explode_above = if player.is(OperatorClass::Ghost) { player::GHOST_EXPLODE_ABOVE } else { (40, 85) };
+    HackOdds { cost, threshold, explode_above }
+}
+
 // Function to attempt hacking a firewall node
-pub fn attempt_hack_node(player: &mut Player, node: &mut FirewallNode) -> bool {
+pub fn attempt_hack_node(player: &mut Player, node: &mut FirewallNode, log: &mut Vec<String>) -> bool {
     let (r, c) = node.coord; // Get node coordinates
 
     // If already compromised
     match node.state {
-        NodeState::Compromised => {
-            println!("Node ({},{}) already compromised.", r, c);
+        NodeState::Compromised if node.owner == Some(player.id) => {
+            log.push(tr!("fw.already_yours", r, c));
             return true;
         }
+        NodeState::Compromised => {
+            log.push(tr!("fw.held_by", r, c, node.owner.map_or(0, |o| o + 1)));
+            return false;
+        }
         NodeState::Exploded => {
-            println!("Node ({},{}) exploded. Avoid.", r, c);
+            log.push(tr!("fw.exploded", r, c));
             return false;
         }
         _ => {}
     }
 
-    // Calculate energy cost to hack
-    let energy_cost = (node.difficulty as i32 / 10) + 5;
+    // Cost and odds, the same numbers a probe shows
+    let odds = hack_odds(player, node);
+    let energy_cost = odds.cost;
 
     if player.energy < energy_cost {
-        println!("Not enough energy for hack: need {}", energy_cost);
+        log.push(tr!("fw.no_energy", energy_cost));
         return false;
     }
 
@@ -114,50 +278,209 @@ pub fn attempt_hack_node(player: &mut Player, node: &mut FirewallNode) -> bool {
 
     let mut rng = rand::thread_rng();
 
-    // Base chance of success
-    let base = if node.state == NodeState::Vulnerable { 50 } else { 20 };
-
-    // Energy bonus improves chance
-    let energy_bonus = clamp_generic(player.energy, 0, 50) as u8;
-
     let roll = rng.gen_range(0..100); // Random roll
-    let threshold = base + (energy_bonus / 2) + (100 - node.difficulty) / 5; // Success threshold
+    let threshold = odds.threshold;   // Success threshold
 
-    println!(
-        "Hack ({},{}): diff={}, cost={}, roll={}, threshold={}",
-        r, c, node.difficulty, energy_cost, roll, threshold
-    );
+    let node_type = node.kind.and_then(|k| content::get().nodes.get(k)); // Content-pack node type
+    if let Some(t) = node_type {
+        log.push(tr!("fw.kind", t.name));
+    }
+    log.push(tr!("fw.roll", r, c, node.difficulty, energy_cost, roll, threshold));
 
     if roll < threshold as u32 {
         node.state = NodeState::Compromised; // Hack succeeded
-        println!("Hack succeeded.");
+        node.owner = Some(player.id);        // Node now belongs to this operator
+        let siphon = node_type.map_or(energy::SIPHON, |t| t.siphon);
+        energy::boost(player, siphon); // Draw power from the node
+        log.push(tr!("fw.success", siphon));
         true
     } else {
         let explode_roll = rng.gen_range(0..100);
-        if explode_roll > 85 { // Chance of honeypot triggering
+        let (trap_above, plain_above) = odds.explode_above;
+        let explode_above = if node.honeypot { trap_above } else { plain_above }; // Honeypots are far more likely to blow
+        if explode_roll > explode_above { // Chance of honeypot triggering
             node.state = NodeState::Exploded;
-            println!("Honeypot triggered! Node exploded.");
+            log.push(tr!("fw.boom"));
         } else {
-            println!("Hack failed.");
+            log.push(tr!("fw.failed"));
         }
         false
     }
 }
 
-// Function to display the firewall grid
-pub fn display_firewall_map(fw: &[FirewallNode], rows: usize, cols: usize) {
-    println!("Firewall (C=Comp, V=Vuln, .=Secure, X=Exploded):");
+// Most promising node to hack: open, in reach, not a flagged honeypot, vulnerable first, then easiest
+pub fn best_target<'a>(fw: &'a [FirewallNode], reach: &dyn Fn(&FirewallNode) -> bool) -> Option<&'a FirewallNode> {
+    fw.iter()
+        .filter(|n| matches!(n.state, NodeState::Secure | NodeState::Vulnerable) && !n.flagged)
+        .filter(|n| reach(n))
+        .min_by_key(|n| (n.state != NodeState::Vulnerable, n.difficulty))
+}
+
+// What a hack on one node would cost and risk, before committing to it
+pub fn probe_lines(player: &Player, node: &FirewallNode, reach: bool) -> Vec<String> {
+    let (r, c) = node.coord;
+    let state = match node.state {
+        NodeState::Compromised if node.owner == Some(player.id) => return vec![tr!("fw.already_yours", r, c)],
+        NodeState::Compromised => return vec![tr!("fw.held_by", r, c, node.owner.map_or(0, |o| o + 1))],
+        NodeState::Exploded => return vec![tr!("fw.exploded", r, c)],
+        NodeState::Vulnerable => tr!("fw.vulnerable"),
+        NodeState::Secure => tr!("fw.secure"),
+    };
+    let odds = hack_odds(player, node);
+    let mut lines = vec![tr!("fw.probe", r, c, state, node.difficulty, odds.cost, player.energy)];
+    if let Some(t) = node.kind.and_then(|k| content::get().nodes.get(k)) {
+        lines.push(tr!("fw.kind", t.name));
+    }
+    lines.push(tr!("fw.probe_odds", odds.threshold));
+    if node.flagged {
+        lines.push(tr!("fw.probe_trap", odds.blast_chance(true), format!("{:.1}", odds.explosion_risk(true))));
+    } else {
+        // Only a grid scan tells a honeypot apart, so both figures are shown
+        lines.push(tr!(
+            "fw.probe_blast",
+            odds.blast_chance(false),
+            format!("{:.1}", odds.explosion_risk(false)),
+            odds.blast_chance(true)
+        ));
+    }
+    if !reach {
+        lines.push(tr!("fw.out_of_reach", r, c));
+    } else if player.energy < odds.cost {
+        lines.push(tr!("fw.no_energy", odds.cost));
+    }
+    lines
+}
+
+// Odds overlay: success %, energy cost and explosion risk % of every open node
+pub fn render_odds_map(
+    fw: &[FirewallNode],
+    rows: usize,
+    cols: usize,
+    player: &Player,
+    reach: &dyn Fn(&FirewallNode) -> bool,
+    style: Style,
+) -> Vec<String> {
+    let mut lines = vec![tr!("fw.odds_legend", player.energy)];
+    if style.screen_reader {
+        for node in fw.iter().filter(|n| matches!(n.state, NodeState::Secure | NodeState::Vulnerable)) {
+            let odds = hack_odds(player, node);
+            let mut notes = String::new();
+            if node.flagged {
+                notes.push_str(&tr!("fw.sr_known_trap"));
+            }
+            if !reach(node) {
+                notes.push_str(&tr!("fw.sr_out_of_reach"));
+            }
+            lines.push(tr!(
+                "fw.sr_odds",
+                node.coord.0,
+                node.coord.1,
+                odds.threshold,
+                odds.cost,
+                odds.explosion_risk(node.flagged).round(),
+                notes
+            ));
+        }
+    } else {
+        for r in 0..rows {
+            let mut row = String::new();
+            for c in 0..cols {
+                let node = &fw[r * cols + c];
+                let odds = hack_odds(player, node);
+                let (cell, role) = match node.state {
+                    NodeState::Compromised => (format!("{:^9}", 'C'), Role::Compromised),
+                    NodeState::Exploded => (format!("{:^9}", 'X'), Role::Exploded),
+                    _ => {
+                        let cell = format!("{:>3}/{:>2}/{:>2}", odds.threshold, odds.cost, odds.explosion_risk(node.flagged).round());
+                        let role = match node.state {
+                            _ if node.flagged => Role::Honeypot,
+                            NodeState::Vulnerable => Role::Vulnerable,
+                            _ => Role::Secure,
+                        };
+                        (cell, role)
+                    }
+                };
+                row.push_str(&style.theme.paint(&cell, role));
+                let open = matches!(node.state, NodeState::Secure | NodeState::Vulnerable);
+                row.push(if node.flagged { 'H' } else { ' ' });
+                row.push(if open && !reach(node) { '-' } else { ' ' });
+                row.push(' ');
+            }
+            lines.push(row);
+        }
+    }
+    lines.push(tr!("fw.odds_note"));
+    lines
+}
+
+// Function to render node difficulties as text lines (sysadmin view)
+pub fn render_difficulty_map(fw: &[FirewallNode], rows: usize, cols: usize) -> Vec<String> {
+    let mut lines = vec![tr!("fw.difficulty_map")];
+    for r in 0..rows {
+        let row: Vec<String> = (0..cols).map(|c| format!("{:>3}", fw[r * cols + c].difficulty)).collect();
+        lines.push(row.join(""));
+    }
+    lines
+}
+
+// Function to render the firewall grid as text lines
+pub fn render_firewall_map(
+    fw: &[FirewallNode],
+    rows: usize,
+    cols: usize,
+    show_owners: bool,
+    reveal_traps: bool,
+    style: Style,
+) -> Vec<String> {
+    if style.screen_reader {
+        return describe_firewall(fw, show_owners, reveal_traps);
+    }
+    let mut lines = Vec::with_capacity(rows + 1); // Legend plus one line per row
+    lines.push(if show_owners { tr!("fw.legend_owners") } else { tr!("fw.legend") });
     for r in 0..rows {
+        let mut row = String::new();
         for c in 0..cols {
             let idx = r * cols + c;
-            let ch = match fw[idx].state {
-                NodeState::Compromised => 'C',
-                NodeState::Vulnerable => 'V',
-                NodeState::Secure => '.',
-                NodeState::Exploded => 'X',
+            let (ch, role) = match fw[idx].state {
+                NodeState::Compromised => match fw[idx].owner {
+                    Some(o) if show_owners => (char::from(b'1' + o as u8), Role::Compromised), // Owner seat number
+                    _ => ('C', Role::Compromised),
+                },
+                NodeState::Exploded => ('X', Role::Exploded),
+                _ if fw[idx].flagged || (reveal_traps && fw[idx].honeypot) => ('H', Role::Honeypot), // Exposed trap
+                NodeState::Vulnerable => ('V', Role::Vulnerable),
+                NodeState::Secure => ('.', Role::Secure),
             };
-            print!("{} ", ch);
+            row.push_str(&style.theme.paint(&ch.to_string(), role));
+            row.push(' ');
         }
-        println!();
+        lines.push(row);
     }
+    lines
+}
+
+// Screen-reader version of the map: nodes listed by state instead of drawn
+fn describe_firewall(fw: &[FirewallNode], show_owners: bool, reveal_traps: bool) -> Vec<String> {
+    let list = |keep: &dyn Fn(&FirewallNode) -> bool, owners: bool| -> String {
+        let nodes: Vec<String> = fw
+            .iter()
+            .filter(|n| keep(n))
+            .map(|n| match n.owner {
+                Some(o) if owners => tr!("fw.sr_owned", n.coord.0, n.coord.1, o + 1),
+                _ => tr!("fw.sr_node", n.coord.0, n.coord.1),
+            })
+            .collect();
+        if nodes.is_empty() { tr!("fw.sr_none") } else { nodes.join("; ") }
+    };
+    let trap = |n: &FirewallNode| n.flagged || (reveal_traps && n.honeypot);
+    let open = |n: &FirewallNode| matches!(n.state, NodeState::Secure | NodeState::Vulnerable);
+    let secure = fw.iter().filter(|n| n.state == NodeState::Secure && !trap(n)).count();
+    vec![
+        tr!("fw.sr_size", fw.len()),
+        tr!("fw.sr_compromised", list(&|n| n.state == NodeState::Compromised, show_owners)),
+        tr!("fw.sr_vulnerable", list(&|n| n.state == NodeState::Vulnerable && !trap(n), false)),
+        tr!("fw.sr_honeypots", list(&|n| open(n) && trap(n), false)),
+        tr!("fw.sr_exploded", list(&|n| n.state == NodeState::Exploded, false)),
+        tr!("fw.sr_secure", secure),
+    ]
 }
@@ -0,0 +1,147 @@
+/*
+DATA TYPES USED:
+
+1. ScanTarget           -> Enum: Any, Code, Grid
+2. code                 -> &[u8; 4], the secret vault code
+3. kinds                -> Vec<Vec<CodeClue>>, true clues grouped by kind
+4. candidates           -> Vec<[u8; 4]>, codes consistent with the player's notes
+5. informative          -> Vec<Vec<CodeClue>>, clue kinds that eliminate at least one candidate
+6. clue                 -> CodeClue, selected clue
+7. fw                   -> &mut [FirewallNode], firewall nodes
+8. traps / weak         -> Vec<usize>, indices of hidden honeypots / secure nodes
+9. rng                  -> ThreadRng, random number generator
+10. samples             -> usize, clues weighed before the sharpest is kept (cryptanalysis)
+11. on                  -> HintOn (from content), which grid scan result a hint describes
+12. args                -> Vec<String>, row, column and difficulty filled into content-pack wording
+13. tr!                 -> macro (from i18n), message text in the chosen language
+*/
+
+use rand::Rng; // Random number generator trait
+
+use super::code::{digit_product, has_repeat, CodeClue}; // Clue enum and helpers
+use super::firewall::{FirewallNode, NodeState};          // Firewall components
+use crate::notebook::Notebook;                           // Player's notes
+use crate::content::{self, HintOn};                      // Content-pack hint wording
+use crate::i18n::{self, tr};                             // Fill placeholders, translated text
+
+// Enum for what a scan is aimed at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanTarget {
+    Any,  // Broad sweep: either kind of hint, flat cost
+    Code, // Vault code hints only
+    Grid, // Firewall hints only
+}
+
+// Build every true clue about the code, grouped by kind
+fn code_catalogue(code: &[u8; 4]) -> Vec<Vec<CodeClue>> {
+    let mut exact = Vec::new();    // DigitAt clues
+    let mut parity = Vec::new();   // DigitParity clues
+    let mut bounds = Vec::new();   // DigitAbove / DigitBelow clues
+
+    for (pos, &digit) in code.iter().enumerate() {
+        exact.push(CodeClue::DigitAt(pos, digit));
+        parity.push(CodeClue::DigitParity(pos, digit.is_multiple_of(2)));
+        for n in 0..digit {
+            bounds.push(CodeClue::DigitAbove(pos, n));
+        }
+        for n in digit + 1..=9 {
+            bounds.push(CodeClue::DigitBelow(pos, n));
+        }
+    }
+
+    vec![
+        exact,
+        vec![CodeClue::DigitSum(code.iter().sum())],
+        parity,
+        bounds,
+        vec![CodeClue::HasRepeat(has_repeat(code))],
+        vec![CodeClue::DigitProduct(digit_product(code))],
+    ]
+}
+
+// Pick a true clue that rules out at least one code the player still considers possible;
+// with several samples the one that rules out the most candidates is kept
+pub fn pick_code_clue(code: &[u8; 4], notebook: &Notebook, samples: usize) -> Option<CodeClue> {
+    let candidates = notebook.candidates();
+
+    // Keep only clues that eliminate something, and drop kinds left empty
+    let informative: Vec<Vec<CodeClue>> = code_catalogue(code)
+        .into_iter()
+        .map(|kind| {
+            kind.into_iter()
+                .filter(|clue| candidates.iter().any(|c| !clue.matches(c)))
+                .collect::<Vec<_>>()
+        })
+        .filter(|kind| !kind.is_empty())
+        .collect();
+
+    if informative.is_empty() {
+        return None; // Notes already pin the code down
+    }
+
+    // Choose a kind first so that numerous bound clues do not crowd out the rest
+    let mut rng = rand::thread_rng();
+    (0..samples.max(1))
+        .map(|_| {
+            let kind = &informative[rng.gen_range(0..informative.len())];
+            kind[rng.gen_range(0..kind.len())]
+        })
+        .max_by_key(|clue| candidates.iter().filter(|c| !clue.matches(c)).count())
+}
+
+// A clue refines earlier notes when it narrows a digit that was already hinted
+pub fn is_refinement(clue: &CodeClue, notebook: &Notebook) -> bool {
+    match clue.position() {
+        Some(pos) => notebook.clues.iter().any(|k| k.clue.position() == Some(pos)),
+        None => false,
+    }
+}
+
+// Reveal something about the firewall; may change node state or flags
+pub fn grid_hint(fw: &mut [FirewallNode]) -> String {
+    let mut rng = rand::thread_rng();
+
+    // Hidden honeypots on nodes that can still be hacked
+    let traps: Vec<usize> = (0..fw.len())
+        .filter(|&i| fw[i].honeypot && !fw[i].flagged && is_open(&fw[i]))
+        .collect();
+    // Secure nodes that are not traps and could have an exploit discovered
+    let weak: Vec<usize> = (0..fw.len())
+        .filter(|&i| fw[i].state == NodeState::Secure && !fw[i].honeypot)
+        .collect();
+
+    if !traps.is_empty() && (weak.is_empty() || rng.gen_bool(0.5)) {
+        let idx = traps[rng.gen_range(0..traps.len())];
+        fw[idx].flagged = true;
+        let (r, c) = fw[idx].coord;
+        let text = tr!("sec.hint_honeypot", r, c);
+        word_hint(&mut rng, HintOn::Honeypot, text, vec![r.to_string(), c.to_string()])
+    } else if !weak.is_empty() {
+        // Report the easiest secure node as the discovered exploit
+        let idx = weak.iter().copied().min_by_key(|&i| fw[i].difficulty).unwrap_or(weak[0]);
+        fw[idx].state = NodeState::Vulnerable;
+        let (r, c) = fw[idx].coord;
+        let text = tr!("sec.hint_exploit", r, c, fw[idx].difficulty);
+        word_hint(&mut rng, HintOn::Exploit, text, vec![r.to_string(), c.to_string(), fw[idx].difficulty.to_string()])
+    } else {
+        let text = tr!("sec.hint_clear");
+        word_hint(&mut rng, HintOn::Clear, text, Vec::new())
+    }
+}
+
+// Built-in wording or, now and then, a content pack's wording for the same result
+fn word_hint(rng: &mut impl Rng, on: HintOn, text: String, args: Vec<String>) -> String {
+    let extra: Vec<&str> = content::get().hints.iter().filter(|h| h.on == on).map(|h| h.text.as_str()).collect();
+    if extra.is_empty() {
+        return text;
+    }
+    match extra.get(rng.gen_range(0..=extra.len())) {
+        Some(template) => i18n::format(template, &args),
+        None => text,
+    }
+}
+
+// Node can still be attacked (not compromised or exploded)
+fn is_open(node: &FirewallNode) -> bool {
+    matches!(node.state, NodeState::Secure | NodeState::Vulnerable)
+}
@@ -0,0 +1,112 @@
+/*
+DATA TYPES USED:
+
+1. LightsLock       -> Struct: a Lights-Out board that opens when every light is off
+2. size             -> usize, board width and height
+3. board            -> Vec<bool>, starting lights (true = on), row by row
+4. presses          -> Vec<(usize, usize)>, cells an operator pressed in one attempt
+5. lit              -> usize, lights still on after an attempt
+6. lines            -> Vec<String>, rendered board
+7. tr!              -> macro (from i18n), message text in the chosen language
+*/
+
+use rand::Rng; // Random number generator
+
+use crate::energy; // Cost table
+use crate::i18n::tr; // Translated text
+use super::puzzle::{parse_cells, Attempt, PuzzleContext, VaultPuzzle}; // Puzzle trait
+use super::Difficulty;                                                   // Difficulty enum
+
+// Struct for the Lights-Out lock
+#[derive(Debug, Clone)]
+pub struct LightsLock {
+    size: usize,      // Board is size x size
+    board: Vec<bool>, // Starting lights, row by row
+}
+
+// Toggle a cell and its orthogonal neighbours
+fn press(board: &mut [bool], size: usize, r: usize, c: usize) {
+    board[r * size + c] ^= true;
+    if r > 0 {
+        board[(r - 1) * size + c] ^= true;
+    }
+    if r + 1 < size {
+        board[(r + 1) * size + c] ^= true;
+    }
+    if c > 0 {
+        board[r * size + c - 1] ^= true;
+    }
+    if c + 1 < size {
+        board[r * size + c + 1] ^= true;
+    }
+}
+
+// Render a board as rows of '#' (on) and '.' (off)
+fn render(board: &[bool], size: usize) -> Vec<String> {
+    board
+        .chunks(size)
+        .map(|row| {
+            let cells: Vec<&str> = row.iter().map(|&on| if on { "#" } else { "." }).collect();
+            format!("  {}", cells.join(" "))
+        })
+        .collect()
+}
+
+impl LightsLock {
+    // Scramble a dark board with random presses, so it can always be solved
+    pub fn new(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let (size, presses) = match difficulty {
+            Difficulty::Easy => (3, 3),
+            Difficulty::Normal => (4, 4),
+            Difficulty::Hard => (4, 6),
+        };
+        let mut board = vec![false; size * size];
+        while !board.iter().any(|&on| on) {
+            for _ in 0..presses {
+                press(&mut board, size, rng.gen_range(0..size), rng.gen_range(0..size));
+            }
+        }
+        LightsLock { size, board }
+    }
+}
+
+impl VaultPuzzle for LightsLock {
+    fn name(&self) -> &'static str {
+        "lights lock"
+    }
+
+    fn label(&self) -> String {
+        tr!("lock.lights")
+    }
+
+    fn describe(&self) -> String {
+        tr!("lock.lights_describe", self.size)
+    }
+
+    fn cost(&self) -> i32 {
+        energy::LIGHTS_PRESS
+    }
+
+    fn attempt(&self, input: &str, _ctx: &PuzzleContext) -> Result<Attempt, String> {
+        let presses = parse_cells(input, self.size, self.size)?;
+        let mut board = self.board.clone();
+        for (r, c) in presses {
+            press(&mut board, self.size, r, c);
+        }
+
+        let lit = board.iter().filter(|&&on| on).count();
+        if lit == 0 {
+            return Ok(Attempt::solved(&tr!("lock.lights_solved")));
+        }
+        let mut lines = vec![tr!("lock.lights_feedback", lit)];
+        lines.extend(render(&board, self.size));
+        Ok(Attempt::failed(lines))
+    }
+
+    // Show the starting board
+    fn reveal_lines(&self, _ctx: &PuzzleContext) -> Vec<String> {
+        let mut lines = vec![tr!("lock.lights_board")];
+        lines.extend(render(&self.board, self.size));
+        lines
+    }
+}
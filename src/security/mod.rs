@@ -16,47 +16,137 @@ DATA TYPES USED:
 13. i32                     -> signed 32-bit integer
 14. Vec<T>                  -> Vector type
 15. RNG                     -> random number generator
+16. ScanHint                -> struct: hint text plus optional CodeClue
+17. ScanTarget              -> enum (from hints module): Any, Code, Grid
+18. code_scans, grid_scans  -> u32, targeted scans performed (cost scaling)
+19. log                     -> &mut Vec<String>, messages for the acting operator
+20. pos, old, new           -> usize / u8, code position being re-keyed and its digits
+21. alert_level             -> u32, how nervous the intrusion detection system is
+22. rotation                -> RotationPolicy (from rotation module), when the code re-keys
+23. turns_since_rekey       -> u32, attacker turns since the code last changed
+24. rekey_log               -> Vec<Vec<usize>>, positions changed by each re-key in order
+25. notices                 -> Vec<String>, IDS news reported alongside a scan
+26. vault                   -> Vault (from vault module), the locks guarding the vault in order
+27. clock                   -> u32, full rounds played (drives the timing lock)
+28. Detection               -> enum (from noise module): Calm, Wary, Alarmed
+29. SecurityEvent           -> enum (from noise module): what a nervous IDS does to the crew
+30. label                   -> String, "(refined) " prefix in the chosen language, or empty
+31. def                     -> &EventDef (from content), a content-pack security event
+32. rekey_rng               -> StdRng, picks re-keyed positions and digits (seeded for the daily challenge)
 */
 
 pub mod firewall; // firewall logic
 pub mod code;     // vault code logic
+pub mod hints;    // scan hint catalogue
+pub mod defense;  // sysadmin (defender) moves
+pub mod rotation; // vault code re-keying
+pub mod vault;    // staged vault locks
+pub mod pattern;  // grid pattern lock
+pub mod timing;   // timing lock
+pub mod puzzle;   // VaultPuzzle trait
+pub mod password; // Wordle-style password lock
+pub mod dial;     // higher/lower dial lock
+pub mod cipher;   // XOR cipher lock
+pub mod lights;   // Lights-Out lock
+pub mod noise;    // detection meter and security events
+pub mod route;    // cheapest hacking route across the grid
 
-use crate::player::Player;                // struct Player
+use crate::player::{self, OperatorClass, Player}; // struct Player and class perks
+use crate::energy::{self, EnergyState};   // cost table and energy states
+use crate::notebook::Notebook;            // struct Notebook (player's notes)
 use crate::utils::{clamp_generic};        // utility function clamp_generic
+use crate::i18n::{self, tr};              // translated messages
+use crate::content;                       // content packs
 use rand::Rng;                             // random number generator trait
+use rand::rngs::StdRng;                    // re-key generator
+use rand::SeedableRng;                     // seed it from entropy
+use std::str::FromStr;                     // parse difficulty names
 
 use firewall::{create_firewall_grid, FirewallNode, NodeState}; // firewall components
+use code::CodeClue;                                              // structured code clue
+use hints::ScanTarget;                                           // scan targeting
+use rotation::{RekeyCause, RotationPolicy};                      // re-key schedule
+use vault::Vault;                                                // staged locks
+use noise::{Detection, SecurityEvent};                           // detection meter
 
 // Difficulty enum (used to determine game settings)
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Difficulty { // enum
     Easy,
     Normal,
     Hard,
 }
 
+// Convert "easy" / "e" etc. into Difficulty
+impl FromStr for Difficulty {
+    type Err = (); // Unit type () used as error
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> { // Result<Difficulty, ()>
+        match s.trim().to_lowercase().as_str() {
+            "e" | "easy" => Ok(Difficulty::Easy),
+            "n" | "normal" => Ok(Difficulty::Normal),
+            "h" | "hard" => Ok(Difficulty::Hard),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Difficulty {
+    // Name in the chosen language
+    pub fn label(&self) -> String { // String
+        match self {
+            Difficulty::Easy => tr!("diff.easy"),
+            Difficulty::Normal => tr!("diff.normal"),
+            Difficulty::Hard => tr!("diff.hard"),
+        }
+    }
+}
+
+// Result of a successful scan
+#[derive(Debug, Clone)]
+pub struct ScanHint { // struct
+    pub text: String,           // String: hint shown to the player
+    pub clue: Option<CodeClue>, // Option<CodeClue>: information about the code, if any
+    pub notices: Vec<String>,   // Vec<String>: re-key news picked up by the scan
+}
+
 // SecuritySystem struct
+#[derive(Debug, Clone)]
 pub struct SecuritySystem { // struct
     pub lock_timer: i32,                   // i32: countdown before lockout
     pub code_digits: [u8; 4],              // [u8; 4]: 4-digit vault code
     pub firewall: Vec<FirewallNode>,       // Vec<FirewallNode>: firewall nodes grid
     pub difficulty: Difficulty,            // Difficulty enum: current difficulty
     pub honeypot_active: bool,             // bool: are honeypots active
+    pub code_scans: u32,                   // u32: targeted code scans so far
+    pub grid_scans: u32,                   // u32: targeted grid scans so far
+    pub alert_level: u32,                  // u32: intrusion detection alert
+    pub rotation: RotationPolicy,          // RotationPolicy: when the code re-keys
+    pub turns_since_rekey: u32,            // u32: turns since the code last changed
+    pub rekey_log: Vec<Vec<usize>>,        // Vec<Vec<usize>>: positions changed per re-key
+    pub vault: Vault,                      // Vault: locks to open, outermost first
+    pub clock: u32,                        // u32: full rounds played
+    pub rekey_rng: StdRng,                 // StdRng: re-key picks (the daily challenge seeds it)
 }
 
 impl SecuritySystem {
     // Constructor for SecuritySystem
     pub fn new(difficulty: Difficulty, rows: usize, cols: usize) -> Self { // returns SecuritySystem
         SecuritySystem {
-            lock_timer: match difficulty {       // i32
-                Difficulty::Easy => 25,
-                Difficulty::Normal => 20,
-                Difficulty::Hard => 16,
-            },
+            lock_timer: content::get().profile(difficulty).lock_timer, // i32: built in or from a content pack
             code_digits: code::generate_code(),        // [u8; 4]: randomly generated code
             firewall: create_firewall_grid(rows, cols, difficulty), // Vec<FirewallNode>
             difficulty,                                // Difficulty
             honeypot_active: true,                     // bool
+            code_scans: 0,                             // u32
+            grid_scans: 0,                             // u32
+            alert_level: 0,                            // u32
+            rotation: RotationPolicy::for_difficulty(difficulty), // RotationPolicy
+            turns_since_rekey: 0,                      // u32
+            rekey_log: Vec::new(),                     // Vec<Vec<usize>>
+            vault: Vault::new(&Vault::default_kinds(difficulty), difficulty, rows, cols), // Vault
+            clock: 0,                                  // u32
+            rekey_rng: StdRng::from_entropy(),         // StdRng
         }
     }
 
@@ -68,50 +158,205 @@ impl SecuritySystem {
             .count()
     }
 
+    // Count compromised nodes held by one operator
+    pub fn owned_nodes(&self, owner: usize) -> usize { // usize
+        self.firewall
+            .iter()
+            .filter(|n| n.state == NodeState::Compromised && n.owner == Some(owner))
+            .count()
+    }
+
+    // Energy an operator pays for the next scan: broad sweeps are flat, targeted scans get dearer each use
+    pub fn scan_cost(&self, player: &Player, target: ScanTarget) -> i32 { // i32
+        let base: i32 = match target {                // i32 before class and skills
+            ScanTarget::Any => energy::SCAN_SWEEP,
+            ScanTarget::Code => {
+                let cost = energy::targeted_scan_cost(energy::SCAN_CODE, self.code_scans);
+                if player.is(OperatorClass::Cracker) { cost - player::CRACKER_SCAN_DISCOUNT } else { cost }
+            }
+            ScanTarget::Grid => energy::targeted_scan_cost(energy::SCAN_GRID, self.grid_scans),
+        };
+        player.cost(base)
+    }
+
     // Scan system for hints
-    pub fn scan(&self, player: &mut Player, last_hint: Option<&String>) -> Option<String> { // Option<String>
-        if player.energy < 8 {                        // i32 comparison
+    pub fn scan(&mut self, player: &mut Player, notebook: &mut Notebook, target: ScanTarget) -> Option<ScanHint> { // Option<ScanHint>
+        let cost: i32 = self.scan_cost(player, target); // i32, after class and skills
+        if player.energy < cost {                     // i32 comparison
             return None;
         }
-        player.energy -= 8;                           // reduce player energy (i32)
+        player.energy -= cost;                        // reduce player energy (i32)
 
-        let mut rng = rand::thread_rng();             // RNG
-        let pick: u8 = rng.gen_range(0..3);           // u8: select hint type
+        // The IDS log tells the scanner about re-keys before any hint is picked
+        let mut notices: Vec<String> = Vec::new();    // Vec<String>
+        notices.extend(self.brief_rekeys(notebook));
+        if let Some(left) = self.rotation.turns_left(self.turns_since_rekey)
+            && left <= rotation::WARN_TURNS
+        {
+            notices.push(tr!("sec.rotation_soon", left));
+        }
 
-        let hint: String = match pick {               // String
-            0 => {                                    // sum of digits hint
-                let sum: u8 = self.code_digits.iter().sum(); // u8
-                format!("Scan hint: sum of digits = {}", sum)
+        // Broad sweeps favour the code two times out of three
+        let aim: ScanTarget = match target {          // ScanTarget
+            ScanTarget::Any => {
+                if rand::thread_rng().gen_range(0..3) < 2 { ScanTarget::Code } else { ScanTarget::Grid }
             }
-            1 => {                                    // specific digit hint
-                let pos: usize = rng.gen_range(0..4);           // usize: position
-                format!("Scan hint: digit {} = {}", pos + 1, self.code_digits[pos])
+            ScanTarget::Code => {
+                self.code_scans += 1;
+                ScanTarget::Code
             }
-            _ => {                                    // compromised nodes hint
-                let cmp: usize = self.compromised_nodes();     // usize
-                format!("Scan hint: {} nodes compromised.", cmp)
+            ScanTarget::Grid => {
+                self.grid_scans += 1;
+                ScanTarget::Grid
             }
         };
 
-        if let Some(prev) = last_hint {               // Option<&String>
-            if prev == &hint {
-                return Some(format!("(refined) {}", hint));
+        if aim == ScanTarget::Grid {
+            let text: String = hints::grid_hint(&mut self.firewall); // String
+            return Some(ScanHint { text, clue: None, notices });
+        }
+
+        match hints::pick_code_clue(&self.code_digits, notebook, player.skills.clue_samples()) { // Option<CodeClue>
+            Some(clue) => {
+                let label: String = if hints::is_refinement(&clue, notebook) { // String
+                    format!("{} ", tr!("sec.refined"))
+                } else {
+                    String::new()
+                };
+                Some(ScanHint {
+                    text: tr!("sec.hint", label, clue.describe()),
+                    clue: Some(clue),
+                    notices,
+                })
             }
+            None => Some(ScanHint {
+                text: tr!("sec.nothing_new"),
+                clue: None,
+                notices,
+            }),
+        }
+    }
+
+    // Number of re-keys so far (notes are stamped with this)
+    pub fn code_epoch(&self) -> u32 { // u32
+        self.rekey_log.len() as u32
+    }
+
+    // Re-key the given code positions, each to a different value
+    pub fn rekey(&mut self, positions: &[usize]) { // ()
+        for &pos in positions {
+            let old: u8 = self.code_digits[pos];                      // u8
+            let new: u8 = (old + self.rekey_rng.gen_range(1..10)) % 10; // u8: never the old digit
+            self.code_digits[pos] = new;
         }
-        Some(hint)
+        self.rekey_log.push(positions.to_vec());
+        self.turns_since_rekey = 0;
+    }
+
+    // Raise the intrusion detection alert
+    pub fn raise_alert(&mut self, amount: u32) { // ()
+        self.alert_level += amount;
+    }
+
+    // End-of-turn rotation check; returns the re-keyed positions and why
+    pub fn tick_rotation(&mut self) -> Option<(Vec<usize>, RekeyCause)> { // Option<(Vec<usize>, RekeyCause)>
+        self.turns_since_rekey += 1;
+        let due: Option<RekeyCause> = self.rotation.due(self.turns_since_rekey, self.alert_level);
+        let rekeyed = due.map(|cause| {
+            let positions: Vec<usize> = rotation::pick_positions(&mut self.rekey_rng, self.rotation.digits);
+            self.rekey(&positions);
+            if cause == RekeyCause::Alert {
+                self.alert_level /= 2; // the IDS calms down once the code is fresh
+            }
+            (positions, cause)
+        });
+        self.alert_level = self.alert_level.saturating_sub(noise::ALERT_DECAY);
+        rekeyed
+    }
+
+    // Detection band for the current alert level
+    pub fn detection(&self) -> Detection { // Detection
+        Detection::of(self.alert_level)
+    }
+
+    // End-of-turn fallout of a noisy IDS: faster timer drain and random security events
+    pub fn detection_sweep(&mut self, player: &mut Player) -> Vec<String> { // Vec<String>
+        let mut lines: Vec<String> = Vec::new();
+        let drain: i32 = self.detection().timer_drain(); // i32
+        if drain > 0 {
+            self.lock_timer -= drain;
+            lines.push(tr!("sec.alarmed", drain));
+        }
+
+        let mut rng = rand::thread_rng();
+        let event: Option<SecurityEvent> = noise::roll_event(self.alert_level);
+        let held: Vec<usize> = (0..self.firewall.len()) // Vec<usize>: nodes the operator holds
+            .filter(|&i| self.firewall[i].state == NodeState::Compromised && self.firewall[i].owner == Some(player.id))
+            .collect();
+        let weak: Vec<usize> = (0..self.firewall.len()) // Vec<usize>: vulnerable nodes
+            .filter(|&i| self.firewall[i].state == NodeState::Vulnerable)
+            .collect();
+        match event {
+            None => {}
+            Some(SecurityEvent::Patrol) if !held.is_empty() => {
+                let node: &mut FirewallNode = &mut self.firewall[held[rng.gen_range(0..held.len())]];
+                node.state = NodeState::Secure;
+                node.owner = None;
+                lines.push(tr!("sec.patrol", player.name, node.coord.0, node.coord.1));
+            }
+            Some(SecurityEvent::Trace) => {
+                player.energy -= noise::TRACE_DRAIN;
+                lines.push(tr!("sec.trace", player.name, noise::TRACE_DRAIN));
+            }
+            Some(SecurityEvent::Hardening) if !weak.is_empty() => {
+                let node: &mut FirewallNode = &mut self.firewall[weak[rng.gen_range(0..weak.len())]];
+                node.state = NodeState::Secure;
+                node.difficulty = node.difficulty.saturating_add(noise::HARDENING).min(99);
+                lines.push(tr!("sec.hardening", node.coord.0, node.coord.1));
+            }
+            Some(SecurityEvent::Custom(i)) => {
+                let def = &content::get().events[i]; // EventDef from a content pack
+                self.lock_timer += def.timer;
+                player.energy += def.energy;
+                self.alert_level = self.alert_level.saturating_add_signed(def.alert);
+                lines.push(i18n::format(&def.text, std::slice::from_ref(&player.name)));
+            }
+            // Lockdown, or a patrol / hardening with nothing to hit
+            Some(_) => {
+                self.lock_timer -= 1;
+                lines.push(tr!("sec.lockdown"));
+            }
+        }
+        lines
+    }
+
+    // Report re-keys the notebook has not heard of, setting stale notes aside
+    pub fn brief_rekeys(&self, notebook: &mut Notebook) -> Option<String> { // Option<String>
+        if notebook.epoch >= self.code_epoch() {
+            return None;
+        }
+        let missed: &[Vec<usize>] = &self.rekey_log[notebook.epoch as usize..]; // &[Vec<usize>]
+        let mut positions: Vec<usize> = missed.iter().flatten().copied().collect(); // Vec<usize>
+        positions.sort();
+        positions.dedup();
+        let count: usize = missed.len();                                        // usize
+        let dropped: usize = notebook.set_aside(&self.rekey_log);               // usize
+        Some(tr!("sec.rekeyed", count, rotation::describe_positions(&positions), dropped))
     }
 
     // Attempt brute force
-    pub fn brute_force(&mut self, player: &mut Player) -> bool { // returns bool
-        if player.energy < 30 {                    // i32 check
-            println!("Not enough energy!");
+    pub fn brute_force(&mut self, player: &mut Player, log: &mut Vec<String>) -> bool { // returns bool
+        let cost: i32 = player.cost(energy::BRUTE_FORCE); // i32, after power management
+        if player.energy < cost {                  // i32 check
+            log.push(tr!("sec.no_energy"));
             return false;
         }
 
-        player.energy -= 30;                       // reduce energy (i32)
+        let state_bonus: i32 = EnergyState::of(player.energy, player.rated_energy).hack_bonus() / 2; // i32
+        player.energy -= cost;                     // reduce energy (i32)
         player.brute_force_used += 1;              // increment counter (i32)
 
-        let compromised: usize = self.compromised_nodes(); // usize
+        let compromised: usize = self.owned_nodes(player.id); // usize: only this operator's nodes count
 
         let base: i32 = match self.difficulty {   // i32 base chance
             Difficulty::Easy => 30,
@@ -120,52 +365,30 @@ impl SecuritySystem {
         };
 
         let extra: i32 = compromised as i32 * 5;  // extra chance based on compromised nodes
-        let threshold: i32 = clamp_generic(base + extra, 0, 90); // clamp between 0-90
+        let class_bonus: i32 = if player.is(OperatorClass::Cracker) { player::CRACKER_BRUTE_BONUS } else { 0 }; // i32
+        let threshold: i32 = clamp_generic(base + extra + state_bonus + class_bonus, 0, 90); // clamp between 0-90
 
         let roll: u32 = rand::thread_rng().gen_range(0..100); // random roll
-        println!(
-            "Brute force: compromised={}, threshold={}, roll={}",
-            compromised, threshold, roll
-        );
+        log.push(tr!("sec.brute", compromised, threshold, roll));
 
         roll < threshold as u32
     }
 
-    // Check if victory condition is met
-    pub fn check_victory(&self) -> bool {       // bool
-        let cmp: usize = self.compromised_nodes(); // usize
-        let total: usize = self.firewall.len();   // usize
-
-        cmp * 100 / total >= 30
+    // Nodes that make up 30% of the firewall, the share needed to win
+    pub fn victory_nodes(&self) -> usize { // usize
+        (self.firewall.len() * 30).div_ceil(100)
     }
 
     // Apply penalty for exploded nodes
-    pub fn apply_explosion_penalty(&mut self) { // ()
+    pub fn apply_explosion_penalty(&mut self, log: &mut Vec<String>) { // ()
         let exploded_count: usize =
             self.firewall.iter().filter(|n| n.state == NodeState::Exploded).count(); // usize
         if exploded_count > 0 {
             let penalty: i32 = exploded_count as i32 / 2; // i32
             self.lock_timer -= penalty;                   // reduce lock timer (i32)
             if penalty > 0 {
-                println!(
-                    "Exploded honeypots detected — lock timer accelerated by {}.",
-                    penalty
-                );
+                log.push(tr!("sec.explosion_penalty", penalty));
             }
         }
     }
-
-    // Battery regeneration logic
-    pub fn battery_regen(&self, player: &mut Player) { // ()
-        if player.inventory.contains(&"Battery pack".to_string()) // bool: check inventory
-            && rand::thread_rng().gen_bool(0.07)                 // bool: random chance
-        {
-            player.energy += 8;                                   // i32: restore energy
-            println!("Battery pack restored some energy!");
-        }
-
-        if player.energy > 150 {                                   // i32 max energy
-            player.energy = 150;
-        }
-    }
 }
@@ -0,0 +1,135 @@
+/*
+DATA TYPES USED:
+
+1. Detection        -> Enum: Calm, Wary, Alarmed, how close the IDS is to spotting the crew
+2. SecurityEvent    -> Enum: Patrol, Trace, Hardening, Lockdown, Custom (a content-pack event)
+3. level            -> u32, IDS alert level (the detection meter)
+4. chance           -> u32, percent chance of a security event this turn
+5. drain            -> i32, extra lock timer ticks lost this turn
+6. pick             -> u32, weighted roll over built-in and content-pack events
+7. ALERT_*          -> u32 constants, noise each action makes and how fast it fades
+8. tr!              -> macro (from i18n), message text in the chosen language
+*/
+
+use rand::Rng; // Random number generator
+
+use crate::i18n::tr; // Translated text
+
+use crate::content::{self, BUILT_IN_WEIGHT}; // Content-pack events
+
+// Alert at which the IDS turns wary / alarmed
+pub const WARY_AT: u32 = 15;
+pub const ALARMED_AT: u32 = 35;
+
+// Most likely a security event can get, in percent (the chance is half the alert level)
+pub const EVENT_CAP: u32 = 30;
+
+// Noise each action makes, raising the IDS alert
+pub const ALERT_SCAN: u32 = 1;          // Every scan pings the IDS a little
+pub const ALERT_HACK: u32 = 2;          // Even a clean intrusion leaves traces
+pub const ALERT_FAILED_GUESS: u32 = 3;  // Wrong code typed at the vault
+pub const ALERT_FAILED_HACK: u32 = 6;   // Node resisted the intrusion
+pub const ALERT_EXPLOSION: u32 = 10;    // Extra when a node blows up
+pub const ALERT_BRUTE_FORCE: u32 = 12;  // Hammering the vault directly
+
+// Alert that fades at the end of every turn
+pub const ALERT_DECAY: u32 = 2;
+
+// Extra alert that fades when an operator lies low
+pub const ALERT_LIE_LOW: u32 = 8;
+
+// Energy a trace drains from the operator
+pub const TRACE_DRAIN: i32 = 6;
+
+// Difficulty added to a node the sysadmins harden
+pub const HARDENING: u8 = 10;
+
+// Enum for the detection meter's bands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Detection {
+    Calm,    // Nothing happens
+    Wary,    // Security events may fire
+    Alarmed, // Events, and the lock timer drains faster
+}
+
+// Enum for random events a nervous IDS sets off
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecurityEvent {
+    Patrol,    // A sweep kicks the operator off one of their nodes
+    Trace,     // A trace drains the operator's energy
+    Hardening, // A vulnerable node is patched and hardened
+    Lockdown,  // The lock timer loses a tick
+    Custom(usize), // A content-pack event (index into Content::events)
+}
+
+impl Detection {
+    // Band for an alert level
+    pub fn of(level: u32) -> Self {
+        if level >= ALARMED_AT {
+            Detection::Alarmed
+        } else if level >= WARY_AT {
+            Detection::Wary
+        } else {
+            Detection::Calm
+        }
+    }
+
+    // Tag shown next to the alert figure
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Detection::Calm => "calm",
+            Detection::Wary => "wary",
+            Detection::Alarmed => "ALARMED",
+        }
+    }
+
+    // Band name shown to players, in the chosen language (scripts read `tag`)
+    pub fn label(&self) -> String {
+        match self {
+            Detection::Calm => tr!("sec.calm"),
+            Detection::Wary => tr!("sec.wary"),
+            Detection::Alarmed => tr!("sec.alarmed_tag"),
+        }
+    }
+
+    // Extra lock timer ticks lost at the end of each turn
+    pub fn timer_drain(&self) -> i32 {
+        if *self == Detection::Alarmed { 1 } else { 0 }
+    }
+}
+
+// Percent chance of a security event this turn
+pub fn event_chance(level: u32) -> u32 {
+    if Detection::of(level) == Detection::Calm {
+        0
+    } else {
+        (level / 2).min(EVENT_CAP)
+    }
+}
+
+// Roll for a security event at this alert level
+pub fn roll_event(level: u32) -> Option<SecurityEvent> {
+    let mut rng = rand::thread_rng();
+    if rng.gen_range(0..100) >= event_chance(level) {
+        return None;
+    }
+    // Built-in events first, then content-pack events by weight
+    let events = &content::get().events;
+    let total = 4 * BUILT_IN_WEIGHT + events.iter().map(|e| e.weight).sum::<u32>();
+    let mut pick = rng.gen_range(0..total);
+    if pick >= 4 * BUILT_IN_WEIGHT {
+        pick -= 4 * BUILT_IN_WEIGHT;
+        for (i, e) in events.iter().enumerate() {
+            if pick < e.weight {
+                return Some(SecurityEvent::Custom(i));
+            }
+            pick -= e.weight;
+        }
+    }
+    Some(match pick / BUILT_IN_WEIGHT {
+        0 => SecurityEvent::Patrol,
+        1 => SecurityEvent::Trace,
+        2 => SecurityEvent::Hardening,
+        _ => SecurityEvent::Lockdown,
+    })
+}
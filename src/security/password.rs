@@ -0,0 +1,113 @@
+/*
+DATA TYPES USED:
+
+1. PasswordLock     -> Struct: a 5-letter admin password guessed Wordle-style
+2. WORDS            -> &[&str], the admin's word list the password is drawn from
+3. secret, guess    -> [u8; 5], password and attempt as uppercase ASCII letters
+4. marks            -> [char; 5], '+' right place, '?' elsewhere in the word, '-' not in the word
+5. spare            -> [usize; 26], unmatched letters of the password, by letter
+6. tr!              -> macro (from i18n), message text in the chosen language
+*/
+
+use rand::seq::SliceRandom; // Pick a random word
+use rand::Rng;              // Random number generator
+
+use crate::energy; // Cost table
+use crate::i18n::tr; // Translated text
+use super::puzzle::{Attempt, PuzzleContext, VaultPuzzle}; // Puzzle trait
+
+// 5-letter words the sysadmin picks passwords from (also used by the cipher lock)
+pub const WORDS: &[&str] = &[
+    "ADMIN", "ALERT", "BLADE", "BLOCK", "BYTES", "CACHE", "CHAIN", "CODEC", "CRACK", "CRYPT",
+    "DELTA", "DRONE", "FLASH", "FRAME", "GHOST", "GATES", "HACKS", "INDEX", "LOGIN", "LASER",
+    "LOGIC", "MACRO", "MODEM", "NEXUS", "NODES", "PANEL", "PIXEL", "PROXY", "QUERY", "RELAY",
+    "ROUTE", "SHELL", "SPOOF", "STACK", "TOKEN", "TRACE", "VAULT", "VIPER", "VIRUS", "WATCH",
+];
+
+// Struct for the Wordle-style password lock
+#[derive(Debug, Clone)]
+pub struct PasswordLock {
+    secret: [u8; 5], // Password, uppercase ASCII
+}
+
+// Parse a 5-letter word into uppercase ASCII
+fn parse_word(input: &str) -> Option<[u8; 5]> {
+    let word = input.trim().to_uppercase();
+    if !word.bytes().all(|b| b.is_ascii_uppercase()) {
+        return None;
+    }
+    word.as_bytes().try_into().ok()
+}
+
+// Wordle marks for a guess against the secret
+pub fn password_marks(secret: &[u8; 5], guess: &[u8; 5]) -> [char; 5] {
+    let mut marks = ['-'; 5];
+    let mut spare = [0usize; 26];
+
+    // Exact letters first, counting the rest of the password's letters
+    for i in 0..5 {
+        if guess[i] == secret[i] {
+            marks[i] = '+';
+        } else {
+            spare[(secret[i] - b'A') as usize] += 1;
+        }
+    }
+
+    // Misplaced letters use up the spare counts left to right
+    for i in 0..5 {
+        let letter = (guess[i] - b'A') as usize;
+        if marks[i] != '+' && spare[letter] > 0 {
+            spare[letter] -= 1;
+            marks[i] = '?';
+        }
+    }
+    marks
+}
+
+impl PasswordLock {
+    // Random 5-letter password from the word list
+    pub fn new(rng: &mut impl Rng) -> Self {
+        let word = WORDS.choose(rng).copied().unwrap_or("VAULT");
+        PasswordLock {
+            secret: parse_word(word).unwrap_or(*b"VAULT"),
+        }
+    }
+}
+
+impl Default for PasswordLock {
+    fn default() -> Self {
+        Self::new(&mut rand::thread_rng())
+    }
+}
+
+impl VaultPuzzle for PasswordLock {
+    fn name(&self) -> &'static str {
+        "password lock"
+    }
+
+    fn label(&self) -> String {
+        tr!("lock.password")
+    }
+
+    fn describe(&self) -> String {
+        tr!("lock.password_describe")
+    }
+
+    fn cost(&self) -> i32 {
+        energy::PASSWORD_GUESS
+    }
+
+    fn attempt(&self, input: &str, _ctx: &PuzzleContext) -> Result<Attempt, String> {
+        let guess = parse_word(input).ok_or_else(|| tr!("lock.password_length"))?;
+        if guess == self.secret {
+            return Ok(Attempt::solved(&tr!("lock.password_solved")));
+        }
+        let marks: String = password_marks(&self.secret, &guess).iter().collect();
+        Ok(Attempt::failed(vec![tr!("lock.password_feedback", String::from_utf8_lossy(&guess), marks)]))
+    }
+
+    // Show the word list the password comes from
+    fn reveal_lines(&self, _ctx: &PuzzleContext) -> Vec<String> {
+        vec![tr!("lock.password_words", WORDS.join(" "))]
+    }
+}
@@ -0,0 +1,133 @@
+/*
+DATA TYPES USED:
+
+1. PatternLock      -> Struct: a secret path of firewall nodes
+2. path             -> Vec<(usize, usize)>, nodes of the pattern in order
+3. trace            -> Vec<(usize, usize)>, nodes an operator traced
+4. rows, cols       -> usize, firewall grid size
+5. in_place         -> usize, traced nodes at the right step of the path
+6. on_path          -> usize, traced nodes on the path but at another step
+7. owner            -> usize, seat id of the operator tracing
+8. Result<Vec<(usize, usize)>, String> -> parsed trace, or why it was refused
+9. ctx              -> &PuzzleContext, game state the lock reads
+10. tr!             -> macro (from i18n), message text in the chosen language
+*/
+
+use rand::Rng; // Random number generator
+
+use crate::energy; // Cost table
+use crate::i18n::tr; // Translated text
+use super::firewall::{neighbours, FirewallNode, NodeState}; // Firewall components and adjacency
+use super::puzzle::{parse_cells, Attempt, PuzzleContext, VaultPuzzle}; // Puzzle trait
+
+// Struct for the grid pattern lock
+#[derive(Debug, Clone)]
+pub struct PatternLock {
+    pub path: Vec<(usize, usize)>, // Secret path, each step next to the last
+}
+
+// Whether two nodes touch horizontally or vertically
+fn adjacent(a: (usize, usize), b: (usize, usize)) -> bool {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1) == 1
+}
+
+impl PatternLock {
+    // Random path of `length` distinct, adjacent nodes
+    pub fn new(rng: &mut impl Rng, length: usize, rows: usize, cols: usize) -> Self {
+        loop {
+            let mut path = vec![(rng.gen_range(0..rows), rng.gen_range(0..cols))];
+            while path.len() < length {
+                let last = path[path.len() - 1];
+                let next: Vec<(usize, usize)> = neighbours(last, rows, cols)
+                    .into_iter()
+                    .filter(|n| !path.contains(n))
+                    .collect();
+                if next.is_empty() {
+                    break; // Walked into a corner: start over
+                }
+                path.push(next[rng.gen_range(0..next.len())]);
+            }
+            if path.len() == length {
+                return PatternLock { path };
+            }
+        }
+    }
+
+    // Nodes held by this operator that the path passes through
+    pub fn foothold(&self, firewall: &[FirewallNode], cols: usize, owner: usize) -> Vec<(usize, usize)> {
+        self.path
+            .iter()
+            .copied()
+            .filter(|&(r, c)| {
+                let node = &firewall[r * cols + c];
+                node.state == NodeState::Compromised && node.owner == Some(owner)
+            })
+            .collect()
+    }
+
+    // Parse "r c r c ..." into a trace of the right length
+    pub fn parse_trace(&self, input: &str, rows: usize, cols: usize) -> Result<Vec<(usize, usize)>, String> {
+        let trace = parse_cells(input, rows, cols)?;
+        if trace.len() != self.path.len() {
+            return Err(tr!("lock.pattern_length", self.path.len()));
+        }
+        if trace.windows(2).any(|w| !adjacent(w[0], w[1])) {
+            return Err(tr!("lock.pattern_adjacent"));
+        }
+        if trace.iter().enumerate().any(|(i, n)| trace[..i].contains(n)) {
+            return Err(tr!("lock.pattern_twice"));
+        }
+        Ok(trace)
+    }
+
+    // Compare a trace with the path: (right step, on the path at another step)
+    pub fn feedback(&self, trace: &[(usize, usize)]) -> (usize, usize) {
+        let in_place = trace.iter().zip(&self.path).filter(|(t, p)| t == p).count();
+        let on_path = trace.iter().filter(|n| self.path.contains(n)).count() - in_place;
+        (in_place, on_path)
+    }
+}
+
+impl VaultPuzzle for PatternLock {
+    fn name(&self) -> &'static str {
+        "pattern lock"
+    }
+
+    fn label(&self) -> String {
+        tr!("lock.pattern")
+    }
+
+    fn describe(&self) -> String {
+        tr!("lock.pattern_describe", self.path.len())
+    }
+
+    fn cost(&self) -> i32 {
+        energy::PATTERN_TRACE
+    }
+
+    fn attempt(&self, input: &str, ctx: &PuzzleContext) -> Result<Attempt, String> {
+        let trace = self.parse_trace(input, ctx.rows, ctx.cols)?;
+        let (in_place, on_path) = self.feedback(&trace);
+        if in_place == self.path.len() {
+            return Ok(Attempt::solved(&tr!("lock.pattern_solved")));
+        }
+        let mut lines = vec![tr!("lock.pattern_feedback", in_place, on_path)];
+        lines.extend(self.reveal_lines(ctx));
+        Ok(Attempt::failed(lines))
+    }
+
+    // Point out pattern nodes the operator already holds
+    fn reveal_lines(&self, ctx: &PuzzleContext) -> Vec<String> {
+        let held = self.foothold(ctx.firewall, ctx.cols, ctx.owner);
+        if held.is_empty() {
+            return Vec::new();
+        }
+        vec![tr!("lock.pattern_held", format_nodes(&held))]
+    }
+}
+
+// Format nodes as "(1,2) (1,3)"
+pub fn format_nodes(nodes: &[(usize, usize)]) -> String {
+    let list: Vec<String> = nodes.iter().map(|(r, c)| format!("({},{})", r, c)).collect();
+    list.join(" ")
+}
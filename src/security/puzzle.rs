@@ -0,0 +1,91 @@
+/*
+DATA TYPES USED:
+
+1. VaultPuzzle      -> Trait: a vault lock solved through "guess ..." attempts
+2. PuzzleContext    -> Struct: read-only game state a puzzle may look at
+3. Attempt          -> Struct: feedback lines and whether the lock opened
+4. input            -> &str, text typed after "guess"
+5. firewall         -> &[FirewallNode], shared firewall grid
+6. owner            -> usize, seat id of the operator making the attempt
+7. clock            -> u32, full rounds played
+8. Result<Attempt, String> -> feedback, or why the input does not fit the puzzle
+9. tr!                     -> macro (from i18n), message text in the chosen language
+*/
+
+use std::fmt::Debug; // Puzzles show up in the vault's debug output
+
+use super::firewall::FirewallNode; // Firewall components
+use crate::i18n::tr; // Translated text
+
+// Game state a puzzle may read while judging an attempt
+pub struct PuzzleContext<'a> {
+    pub firewall: &'a [FirewallNode], // Shared firewall grid
+    pub rows: usize,                  // Firewall rows
+    pub cols: usize,                  // Firewall columns
+    pub owner: usize,                 // Seat id of the operator at the lock
+    pub clock: u32,                   // Full rounds played
+}
+
+// Result of one accepted attempt
+#[derive(Debug, Default)]
+pub struct Attempt {
+    pub lines: Vec<String>, // Feedback for the operator
+    pub solved: bool,       // Whether the lock opened
+}
+
+impl Attempt {
+    // An attempt that opened the lock
+    pub fn solved(line: &str) -> Self {
+        Attempt {
+            lines: vec![line.to_string()],
+            solved: true,
+        }
+    }
+
+    // An attempt that left the lock shut
+    pub fn failed(lines: Vec<String>) -> Self {
+        Attempt { lines, solved: false }
+    }
+}
+
+// A vault lock that is opened by typing attempts at it.
+// Attempts never change the puzzle, so every operator can work the same lock.
+pub trait VaultPuzzle: Debug {
+    // English name handed to scripts, e.g. "pattern lock"
+    fn name(&self) -> &'static str;
+
+    // Name in the chosen language, used in announcements
+    fn label(&self) -> String;
+
+    // Text shown when the lock is revealed: what it is and how to answer it
+    fn describe(&self) -> String;
+
+    // Energy spent by one attempt
+    fn cost(&self) -> i32;
+
+    // Judge one attempt; Err if the input does not fit (no energy or turn used)
+    fn attempt(&self, input: &str, ctx: &PuzzleContext) -> Result<Attempt, String>;
+
+    // Extra lines shown alongside the description (e.g. the starting board)
+    fn reveal_lines(&self, _ctx: &PuzzleContext) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+// Parse "r c r c ..." into coordinates inside a rows x cols grid
+pub fn parse_cells(input: &str, rows: usize, cols: usize) -> Result<Vec<(usize, usize)>, String> {
+    let numbers: Vec<usize> = input
+        .split_whitespace()
+        .map(|w| w.parse::<usize>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| tr!("lock.cells"))?;
+    if numbers.is_empty() || !numbers.len().is_multiple_of(2) {
+        return Err(tr!("lock.cells"));
+    }
+
+    let cells: Vec<(usize, usize)> = numbers.chunks(2).map(|p| (p[0], p[1])).collect();
+    if cells.iter().any(|&(r, c)| r >= rows || c >= cols) {
+        return Err(tr!("lock.cells_range"));
+    }
+    Ok(cells)
+}
@@ -0,0 +1,116 @@
+/*
+DATA TYPES USED:
+
+1. RotationPolicy   -> Struct: when the vault re-keys and how many digits change
+2. RekeyCause       -> Enum: Scheduled, Alert
+3. every_turns      -> u32, turns between scheduled re-keys (0 = never)
+4. alert_threshold  -> u32, alert level that forces a re-key (0 = never)
+5. digits           -> usize, code positions changed by one automatic re-key
+6. turns, alert     -> u32, turns since the last re-key / current alert level
+7. positions        -> Vec<usize>, distinct code positions (0-based)
+8. rng              -> impl Rng, the security system's re-key generator
+*/
+
+use rand::seq::index::sample; // Distinct random positions
+use rand::Rng;                // Random number generator
+
+use super::Difficulty; // Difficulty enum
+
+// Scheduled re-keys this close are flagged by scans
+pub const WARN_TURNS: u32 = 3;
+
+// Struct describing when the vault changes its code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RotationPolicy {
+    pub every_turns: u32,     // Turns between scheduled re-keys (0 = never)
+    pub alert_threshold: u32, // Alert level that forces a re-key (0 = never)
+    pub digits: usize,        // Positions changed by one automatic re-key
+}
+
+// Enum for why an automatic re-key happened
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RekeyCause {
+    Scheduled, // The rotation interval ran out
+    Alert,     // The IDS alert level spiked
+}
+
+impl RotationPolicy {
+    // Default policy for a difficulty level
+    pub fn for_difficulty(difficulty: Difficulty) -> Self {
+        match difficulty {
+            Difficulty::Easy => RotationPolicy { every_turns: 0, alert_threshold: 60, digits: 1 },
+            Difficulty::Normal => RotationPolicy { every_turns: 12, alert_threshold: 45, digits: 1 },
+            Difficulty::Hard => RotationPolicy { every_turns: 8, alert_threshold: 35, digits: 2 },
+        }
+    }
+
+    // Whether a re-key is due after this many turns at this alert level
+    pub fn due(&self, turns: u32, alert: u32) -> Option<RekeyCause> {
+        if self.every_turns > 0 && turns >= self.every_turns {
+            Some(RekeyCause::Scheduled)
+        } else if self.alert_threshold > 0 && alert >= self.alert_threshold {
+            Some(RekeyCause::Alert)
+        } else {
+            None
+        }
+    }
+
+    // Turns left before the next scheduled re-key, if any is scheduled
+    pub fn turns_left(&self, turns: u32) -> Option<u32> {
+        (self.every_turns > 0).then(|| self.every_turns.saturating_sub(turns))
+    }
+}
+
+// Pick which code positions an automatic re-key changes
+pub fn pick_positions(rng: &mut impl Rng, digits: usize) -> Vec<usize> {
+    let mut positions = sample(rng, 4, digits.clamp(1, 4)).into_vec();
+    positions.sort();
+    positions
+}
+
+// Format 0-based positions as "1, 3"
+pub fn describe_positions(positions: &[usize]) -> String {
+    let list: Vec<String> = positions.iter().map(|p| (p + 1).to_string()).collect();
+    list.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn schedule_and_alert_trigger_rekeys() {
+        let policy = RotationPolicy { every_turns: 8, alert_threshold: 35, digits: 2 };
+        assert_eq!(policy.due(7, 34), None);
+        assert_eq!(policy.due(8, 0), Some(RekeyCause::Scheduled));
+        assert_eq!(policy.due(3, 35), Some(RekeyCause::Alert));
+        assert_eq!(policy.due(9, 50), Some(RekeyCause::Scheduled)); // The schedule wins a tie
+        assert_eq!(policy.turns_left(5), Some(3));
+        assert_eq!(policy.turns_left(12), Some(0));
+    }
+
+    #[test]
+    fn zero_switches_a_trigger_off() {
+        let easy = RotationPolicy::for_difficulty(Difficulty::Easy);
+        assert_eq!(easy.turns_left(100), None);
+        assert_eq!(easy.due(100, 59), None);
+        assert_eq!(easy.due(100, 60), Some(RekeyCause::Alert));
+
+        let never = RotationPolicy { every_turns: 0, alert_threshold: 0, digits: 1 };
+        assert_eq!(never.due(u32::MAX, u32::MAX), None);
+    }
+
+    #[test]
+    fn positions_are_distinct_sorted_and_clamped() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for digits in 0..=6 {
+            let positions = pick_positions(&mut rng, digits);
+            assert_eq!(positions.len(), digits.clamp(1, 4));
+            assert!(positions.windows(2).all(|w| w[0] < w[1]));
+            assert!(positions.iter().all(|&p| p < 4));
+        }
+        assert_eq!(describe_positions(&[0, 2]), "1, 3");
+    }
+}
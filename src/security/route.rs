@@ -0,0 +1,170 @@
+/*
+DATA TYPES USED:
+
+1. Route            -> Struct: the nodes to hack on the way to a target and what they cost
+2. steps            -> Vec<(usize, usize)>, nodes still to hack, foothold outward
+3. energy           -> i32, energy the hacks cost together
+4. held             -> &dyn Fn(&FirewallNode) -> bool, nodes the operator (or crew) already holds
+5. target           -> (usize, usize), node the route ends on
+6. cost             -> (i32, u32), energy then total difficulty of the cheapest way to a node
+7. best             -> Vec<Option<(i32, u32)>>, cheapest known cost to each node
+8. from             -> Vec<Option<usize>>, node each cheapest way came from
+9. done             -> Vec<bool>, nodes whose cheapest cost is settled
+*/
+
+use crate::player::Player; // Hack costs depend on the operator
+use super::firewall::{hack_price, neighbours, on_edge, FirewallNode, NodeState}; // Firewall components and adjacency
+
+// Struct for a planned route through the firewall
+#[derive(Debug, Clone)]
+pub struct Route {
+    pub steps: Vec<(usize, usize)>, // Nodes to hack in order (held nodes are passed through free)
+    pub energy: i32,                // Energy the hacks cost before siphons and regen
+}
+
+impl Route {
+    // Each hack uses one turn
+    pub fn turns(&self) -> usize {
+        self.steps.len()
+    }
+}
+
+// Energy and difficulty of hacking a node on the way, None if the route cannot pass it
+fn step_cost(node: &FirewallNode, player: &Player, held: &dyn Fn(&FirewallNode) -> bool, target: usize, idx: usize) -> Option<(i32, u32)> {
+    if held(node) {
+        return Some((0, 0)); // Already ours: walk straight through
+    }
+    let open = matches!(node.state, NodeState::Secure | NodeState::Vulnerable);
+    if !open || (node.flagged && idx != target) {
+        return None; // Taken, blown, or a known honeypot in the way
+    }
+    Some((player.cost(hack_price(player, node.difficulty)), node.difficulty as u32))
+}
+
+// Cheapest route from the foothold to `target` (Dijkstra over adjacent nodes)
+// A route starts from a held node or breaks in at any node on the edge of the grid
+pub fn plan(
+    fw: &[FirewallNode],
+    rows: usize,
+    cols: usize,
+    player: &Player,
+    held: &dyn Fn(&FirewallNode) -> bool,
+    target: (usize, usize),
+) -> Option<Route> {
+    let goal = target.0 * cols + target.1;
+    let mut best: Vec<Option<(i32, u32)>> = vec![None; fw.len()];
+    let mut from: Vec<Option<usize>> = vec![None; fw.len()];
+    let mut done = vec![false; fw.len()];
+
+    for (idx, node) in fw.iter().enumerate() {
+        if held(node) || on_edge(node.coord, rows, cols) {
+            best[idx] = step_cost(node, player, held, goal, idx);
+        }
+    }
+
+    loop {
+        // Settle the cheapest node not yet settled
+        let Some(idx) = (0..fw.len())
+            .filter(|&i| !done[i] && best[i].is_some())
+            .min_by_key(|&i| best[i])
+        else {
+            return None; // Target cannot be reached
+        };
+        done[idx] = true;
+        if idx == goal {
+            break;
+        }
+        let (energy, difficulty) = best[idx]?;
+        for (r, c) in neighbours(fw[idx].coord, rows, cols) {
+            let next = r * cols + c;
+            if done[next] {
+                continue;
+            }
+            let Some((e, d)) = step_cost(&fw[next], player, held, goal, next) else {
+                continue;
+            };
+            let cost = (energy + e, difficulty + d);
+            if best[next].is_none_or(|b| cost < b) {
+                best[next] = Some(cost);
+                from[next] = Some(idx);
+            }
+        }
+    }
+
+    // Walk back from the target, keeping the nodes that still need a hack
+    let mut steps = Vec::new();
+    let mut at = Some(goal);
+    while let Some(idx) = at {
+        if !held(&fw[idx]) {
+            steps.push(fw[idx].coord);
+        }
+        at = from[idx];
+    }
+    steps.reverse();
+    Some(Route { steps, energy: best[goal]?.0 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::Difficulty;
+
+    // Square grid of secure nodes, all with the same difficulty
+    fn grid(size: usize, difficulty: u8) -> Vec<FirewallNode> {
+        (0..size * size)
+            .map(|i| FirewallNode {
+                coord: (i / size, i % size),
+                state: NodeState::Secure,
+                difficulty,
+                honeypot: false,
+                flagged: false,
+                owner: None,
+                kind: None,
+            })
+            .collect()
+    }
+
+    fn ours(node: &FirewallNode) -> bool {
+        node.owner == Some(0)
+    }
+
+    #[test]
+    fn route_takes_the_cheapest_way_in() {
+        let player = Player::new(0, "ana", Difficulty::Easy);
+        let mut fw = grid(3, 8);
+        fw[1].difficulty = 1; // (0, 1) is the soft way in
+        let route = plan(&fw, 3, 3, &player, &ours, (1, 1)).unwrap();
+        assert_eq!(route.steps, vec![(0, 1), (1, 1)]);
+        assert_eq!(route.turns(), 2);
+        let cheap = player.cost(hack_price(&player, 1)) + player.cost(hack_price(&player, 8));
+        assert_eq!(route.energy, cheap);
+    }
+
+    #[test]
+    fn route_starts_from_a_held_node() {
+        let player = Player::new(0, "ana", Difficulty::Easy);
+        let mut fw = grid(3, 5);
+        fw[3].state = NodeState::Compromised;
+        fw[3].owner = Some(0);
+        let route = plan(&fw, 3, 3, &player, &ours, (1, 1)).unwrap();
+        assert_eq!(route.steps, vec![(1, 1)]);
+    }
+
+    #[test]
+    fn walled_off_target_has_no_route() {
+        let player = Player::new(0, "ana", Difficulty::Easy);
+        let mut fw = grid(3, 5);
+        for node in fw.iter_mut().filter(|n| on_edge(n.coord, 3, 3)) {
+            node.state = NodeState::Exploded;
+        }
+        assert!(plan(&fw, 3, 3, &player, &ours, (1, 1)).is_none());
+
+        // Flagged honeypots block the way but can still be the target
+        let mut fw = grid(3, 5);
+        for node in fw.iter_mut().filter(|n| on_edge(n.coord, 3, 3)) {
+            node.flagged = true;
+        }
+        assert!(plan(&fw, 3, 3, &player, &ours, (1, 1)).is_none());
+        assert_eq!(plan(&fw, 3, 3, &player, &ours, (0, 0)).unwrap().steps, vec![(0, 0)]);
+    }
+}
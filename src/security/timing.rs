@@ -0,0 +1,68 @@
+/*
+DATA TYPES USED:
+
+1. TimingLock       -> Struct: a lock that only opens on its pulse
+2. period           -> u32, rounds between pulses
+3. phase            -> u32, round (modulo period) on which the lock pulses
+4. clock            -> u32, rounds played so far (from SecuritySystem)
+5. miss             -> u32, rounds between the attempt and the nearest pulse
+6. max_period       -> u32, longest pulse interval to pick from
+7. tr!              -> macro (from i18n), message text in the chosen language
+*/
+
+use rand::Rng; // Random number generator
+
+use crate::energy; // Cost table
+use crate::i18n::tr; // Translated text
+use super::puzzle::{Attempt, PuzzleContext, VaultPuzzle}; // Puzzle trait
+
+// Struct for the timing lock
+#[derive(Debug, Clone, Copy)]
+pub struct TimingLock {
+    pub period: u32, // Rounds between pulses
+    pub phase: u32,  // Pulse round, modulo the period
+}
+
+impl TimingLock {
+    // Random pulse every 3 to `max_period` rounds
+    pub fn new(rng: &mut impl Rng, max_period: u32) -> Self {
+        let period = rng.gen_range(3..=max_period.max(3));
+        TimingLock {
+            period,
+            phase: rng.gen_range(0..period),
+        }
+    }
+
+    // Rounds to the nearest pulse (0 = on the pulse)
+    pub fn miss(&self, clock: u32) -> u32 {
+        let offset = (clock + self.period - self.phase) % self.period;
+        offset.min(self.period - offset)
+    }
+}
+
+impl VaultPuzzle for TimingLock {
+    fn name(&self) -> &'static str {
+        "timing lock"
+    }
+
+    fn label(&self) -> String {
+        tr!("lock.timing")
+    }
+
+    fn describe(&self) -> String {
+        tr!("lock.timing_describe", self.period)
+    }
+
+    fn cost(&self) -> i32 {
+        energy::TIMING_SYNC
+    }
+
+    // Any input counts as a sync attempt
+    fn attempt(&self, _input: &str, ctx: &PuzzleContext) -> Result<Attempt, String> {
+        let miss = self.miss(ctx.clock);
+        if miss == 0 {
+            return Ok(Attempt::solved(&tr!("lock.timing_solved")));
+        }
+        Ok(Attempt::failed(vec![tr!("lock.timing_feedback", miss)]))
+    }
+}
@@ -0,0 +1,150 @@
+/*
+DATA TYPES USED:
+
+1. LockKind         -> Enum: Code, Pattern, Timing, Password, Dial, Cipher, Lights (names typed on the command line)
+2. Lock             -> Enum: the code lock, or any other VaultPuzzle
+3. Vault            -> Struct: the locks in the order they must be opened
+4. kinds            -> &[LockKind], layers requested for a vault
+5. layer            -> usize, index of a lock (0 = outermost)
+6. opened           -> usize, number of locks an operator has opened so far
+7. Result<Vec<LockKind>, String> -> parsed layer list, or why it was refused
+8. rng              -> impl Rng, picks the puzzles' secrets (seeded for the daily challenge)
+9. tr!              -> macro (from i18n), message text in the chosen language
+*/
+
+use std::rc::Rc;       // Shared puzzle (attempts never change it)
+use std::str::FromStr; // Parse lock names
+
+use rand::Rng; // Random number generator
+
+use super::cipher::CipherLock;     // XOR cipher lock
+use super::dial::DialLock;         // Higher/lower dial lock
+use super::lights::LightsLock;     // Lights-Out lock
+use super::password::PasswordLock; // Wordle-style password lock
+use super::pattern::PatternLock;   // Grid pattern lock
+use super::puzzle::VaultPuzzle;    // Puzzle trait
+use super::timing::TimingLock;     // Timing lock
+use super::Difficulty;             // Difficulty enum
+use crate::i18n::tr;               // Translated text
+
+// Enum naming the kinds of lock a vault can be built from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockKind {
+    Code,     // 4-digit mastermind code (security::code)
+    Pattern,  // Path traced across the firewall grid (security::pattern)
+    Timing,   // Sync with the lock's pulse (security::timing)
+    Password, // Wordle-style 5-letter password (security::password)
+    Dial,     // Higher/lower number search (security::dial)
+    Cipher,   // XOR-encrypted word (security::cipher)
+    Lights,   // Lights-Out board (security::lights)
+}
+
+// Convert "code" / "pattern" / "lights" etc. into LockKind
+impl FromStr for LockKind {
+    type Err = (); // Unit type () used as error
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "code" => Ok(LockKind::Code),
+            "pattern" => Ok(LockKind::Pattern),
+            "timing" => Ok(LockKind::Timing),
+            "password" | "wordle" => Ok(LockKind::Password),
+            "dial" | "higher-lower" => Ok(LockKind::Dial),
+            "cipher" | "xor" => Ok(LockKind::Cipher),
+            "lights" | "lights-out" => Ok(LockKind::Lights),
+            _ => Err(()),
+        }
+    }
+}
+
+// Enum for one layer of the vault
+#[derive(Debug, Clone)]
+pub enum Lock {
+    Code,                        // Secret lives in SecuritySystem::code_digits (re-keyed by rotation)
+    Puzzle(Rc<dyn VaultPuzzle>), // Any other lock
+}
+
+impl Lock {
+    // Text shown when the lock is revealed
+    pub fn describe(&self) -> String {
+        match self {
+            Lock::Code => tr!("lock.code_describe"),
+            Lock::Puzzle(p) => p.describe(),
+        }
+    }
+}
+
+// Struct for a vault made of sequential locks
+#[derive(Debug, Clone)]
+pub struct Vault {
+    pub locks: Vec<Lock>, // Outermost first; each is revealed once the previous one opens
+}
+
+impl Vault {
+    // Build a vault from a list of lock kinds
+    pub fn new(kinds: &[LockKind], difficulty: Difficulty, rows: usize, cols: usize) -> Self {
+        Vault::new_with(&mut rand::thread_rng(), kinds, difficulty, rows, cols)
+    }
+
+    // Build a vault whose secrets come from a given generator (seeded for the daily challenge)
+    pub fn new_with(rng: &mut impl Rng, kinds: &[LockKind], difficulty: Difficulty, rows: usize, cols: usize) -> Self {
+        let (path_length, max_period) = match difficulty {
+            Difficulty::Easy => (3, 3),
+            Difficulty::Normal => (3, 4),
+            Difficulty::Hard => (4, 5),
+        };
+        let locks = kinds
+            .iter()
+            .map(|kind| match kind {
+                LockKind::Code => Lock::Code,
+                LockKind::Pattern => Lock::Puzzle(Rc::new(PatternLock::new(rng, path_length, rows, cols))),
+                LockKind::Timing => Lock::Puzzle(Rc::new(TimingLock::new(rng, max_period))),
+                LockKind::Password => Lock::Puzzle(Rc::new(PasswordLock::new(rng))),
+                LockKind::Dial => Lock::Puzzle(Rc::new(DialLock::new(rng, difficulty))),
+                LockKind::Cipher => Lock::Puzzle(Rc::new(CipherLock::new(rng))),
+                LockKind::Lights => Lock::Puzzle(Rc::new(LightsLock::new(rng, difficulty))),
+            })
+            .collect();
+        Vault { locks }
+    }
+
+    // Layers used when none are chosen at launch
+    pub fn default_kinds(difficulty: Difficulty) -> Vec<LockKind> {
+        match difficulty {
+            Difficulty::Easy => vec![LockKind::Code],
+            Difficulty::Normal => vec![LockKind::Code, LockKind::Pattern],
+            Difficulty::Hard => vec![LockKind::Code, LockKind::Pattern, LockKind::Timing],
+        }
+    }
+
+    // Index of the code lock, if the vault has one
+    pub fn code_layer(&self) -> Option<usize> {
+        self.locks.iter().position(|l| matches!(l, Lock::Code))
+    }
+
+    // Whether an operator with this many locks open is past the code lock
+    pub fn code_open(&self, opened: usize) -> bool {
+        self.code_layer().is_some_and(|i| opened > i)
+    }
+
+    // Status text for an operator with this many locks open
+    pub fn progress(&self, opened: usize) -> String {
+        match self.locks.get(opened) {
+            Some(lock) => tr!("lock.progress", opened + 1, self.locks.len(), lock.describe()),
+            None => tr!("lock.all_open", self.locks.len()),
+        }
+    }
+}
+
+// Parse "code,pattern,timing" into lock kinds (outermost first)
+pub fn parse_kinds(list: &str) -> Result<Vec<LockKind>, String> {
+    let kinds: Vec<LockKind> = list
+        .split(',')
+        .map(|name| name.parse::<LockKind>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| tr!("lock.kinds"))?;
+    if kinds.is_empty() {
+        return Err(tr!("lock.no_locks"));
+    }
+    Ok(kinds)
+}
@@ -0,0 +1,208 @@
+/*
+DATA TYPES USED:
+
+1. Tally            -> Struct: what one operator did during a run (successes and action counts)
+2. Run              -> Struct: one finished run, ready to be added to lifetime stats
+3. Stats            -> Struct: an operator's lifetime stats, saved to disk
+4. record           -> Record (from storage), key=value pairs on disk
+5. kind             -> &str, action name ("scan", "hack", ...)
+6. field            -> &str, counter name ("games", "wins", "hacks_won", ...)
+7. difficulty       -> Difficulty enum, runs are counted per difficulty
+8. filter           -> Option<Difficulty>, limit the report to one difficulty
+9. rate             -> String, success percentage ("63%", or "-" with no attempts)
+10. tr!             -> macro (from i18n), message text in the chosen language
+*/
+
+use std::io;            // Error type
+use std::path::PathBuf; // Stats file location
+
+use crate::security::Difficulty; // Difficulty enum
+use crate::storage::{self, Record}; // Saved data
+use crate::utils::add_count; // Update a saved counter
+use crate::i18n::tr; // Translated text
+
+// Counters kept for every difficulty
+const FIELDS: [&str; 8] = ["games", "wins", "turns", "hacks", "hacks_won", "brutes", "brutes_won", "explosions"];
+
+// Every difficulty, in report order
+const DIFFICULTIES: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+// Favourite actions listed in the report
+const FAVOURITES: usize = 3;
+
+// Struct counting what an operator did during one run
+#[derive(Debug, Clone, Default)]
+pub struct Tally {
+    pub hacks_won: u32,                   // Node hacks that succeeded
+    pub brutes_won: u32,                  // Brute force attempts that opened the code lock
+    pub actions: Vec<(&'static str, u32)>, // Turn-taking actions by name
+}
+
+// Struct describing one finished run
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub difficulty: Difficulty, // Difficulty played
+    pub won: bool,              // Whether the operator won
+    pub hacks: u32,             // Node hacks attempted
+    pub brutes: u32,            // Brute force attempts
+    pub explosions: u32,        // Nodes blown up
+    pub tally: Tally,           // Successes and action counts
+}
+
+// Struct for an operator's lifetime stats
+pub struct Stats {
+    pub handle: String, // Operator handle
+    record: Record,     // "<difficulty>.<field>" and "action.<kind>" counters
+}
+
+impl Tally {
+    // Count one turn-taking action
+    pub fn note(&mut self, kind: &'static str) {
+        match self.actions.iter_mut().find(|(k, _)| *k == kind) {
+            Some((_, n)) => *n += 1,
+            None => self.actions.push((kind, 1)),
+        }
+    }
+
+    // Turns taken
+    pub fn turns(&self) -> u32 {
+        self.actions.iter().map(|(_, n)| n).sum()
+    }
+}
+
+// Where a handle's stats are kept
+fn stats_path(handle: &str) -> PathBuf {
+    storage::data_dir().join("stats").join(format!("{}.txt", storage::file_stem(handle)))
+}
+
+// Lower-case difficulty name used in keys
+fn label(difficulty: Difficulty) -> String {
+    format!("{:?}", difficulty).to_lowercase()
+}
+
+// Success percentage, or "-" with no attempts
+fn rate(won: u32, tries: u32) -> String {
+    (won * 100).checked_div(tries).map_or("-".to_string(), |pct| format!("{}%", pct))
+}
+
+// Average per game to one decimal place
+fn average(total: u32, games: u32) -> String {
+    if games == 0 { "-".to_string() } else { format!("{:.1}", total as f64 / games as f64) }
+}
+
+impl Stats {
+    // Load an operator's stats (missing file = no games yet)
+    pub fn load(handle: &str) -> io::Result<Self> {
+        Ok(Stats {
+            handle: handle.to_string(),
+            record: storage::read_record(&stats_path(handle))?,
+        })
+    }
+
+    // Save the stats file
+    pub fn save(&self) -> io::Result<()> {
+        storage::write_record(&stats_path(&self.handle), &self.record)
+    }
+
+    // Read one counter
+    fn value(&self, key: &str) -> u32 {
+        storage::get_u32(&self.record, key)
+    }
+
+    // Add to one counter
+    fn add(&mut self, key: String, amount: u32) {
+        add_count(&mut self.record, &key, amount);
+    }
+
+    // A counter for one difficulty, or summed over all of them
+    fn total(&self, filter: Option<Difficulty>, field: &str) -> u32 {
+        DIFFICULTIES
+            .into_iter()
+            .filter(|&d| filter.is_none_or(|f| f == d))
+            .map(|d| self.value(&format!("{}.{}", label(d), field)))
+            .sum()
+    }
+
+    // Add a finished run
+    pub fn add_run(&mut self, run: &Run) {
+        let d = label(run.difficulty);
+        let counts = [
+            1,
+            run.won as u32,
+            run.tally.turns(),
+            run.hacks,
+            run.tally.hacks_won,
+            run.brutes,
+            run.tally.brutes_won,
+            run.explosions,
+        ];
+        for (field, amount) in FIELDS.into_iter().zip(counts) {
+            self.add(format!("{}.{}", d, field), amount);
+        }
+        for &(kind, n) in &run.tally.actions {
+            self.add(format!("action.{}", kind), n);
+        }
+    }
+
+    // Load, add one run and save
+    pub fn record_run(handle: &str, run: &Run) -> io::Result<()> {
+        let mut stats = Stats::load(handle)?;
+        stats.add_run(run);
+        stats.save()
+    }
+
+    // Report lines, for every difficulty or just one
+    pub fn summary_lines(&self, filter: Option<Difficulty>) -> Vec<String> {
+        let t = |field: &str| self.total(filter, field);
+        let games = t("games");
+        let mut lines = vec![match filter {
+            Some(d) => tr!("stats.title_for", self.handle, d.label()),
+            None => tr!("stats.title", self.handle),
+        }];
+        if games == 0 {
+            lines.push(format!("  {}", tr!("stats.no_games")));
+            return lines;
+        }
+        lines.push(format!("  {}", tr!("stats.games", games, t("wins"), rate(t("wins"), games))));
+        lines.push(format!("  {}", tr!("stats.turns", average(t("turns"), games))));
+        lines.push(format!("  {}", tr!("stats.hacks", t("hacks"), rate(t("hacks_won"), t("hacks")))));
+        lines.push(format!("  {}", tr!("stats.brutes", t("brutes"), rate(t("brutes_won"), t("brutes")))));
+        lines.push(format!("  {}", tr!("stats.explosions", t("explosions"))));
+
+        if filter.is_none() {
+            // Favourite actions over every game
+            let mut actions: Vec<(&str, u32)> = self
+                .record
+                .iter()
+                .filter_map(|(k, v)| Some((k.strip_prefix("action.")?, v.parse().ok()?)))
+                .collect();
+            actions.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+            let top: Vec<String> = actions.iter().take(FAVOURITES).map(|(k, n)| format!("{} ({})", k, n)).collect();
+            if !top.is_empty() {
+                lines.push(format!("  {}", tr!("stats.favourites", top.join(", "))));
+            }
+
+            lines.push(tr!("stats.by_difficulty"));
+            for d in DIFFICULTIES {
+                let f = |field: &str| self.total(Some(d), field);
+                let games = f("games");
+                if games == 0 {
+                    lines.push(format!("  {:<8} {}", d.label(), tr!("stats.no_games_short")));
+                    continue;
+                }
+                let row = tr!(
+                    "stats.row",
+                    games,
+                    f("wins"),
+                    rate(f("wins"), games),
+                    average(f("turns"), games),
+                    rate(f("hacks_won"), f("hacks")),
+                    rate(f("brutes_won"), f("brutes")),
+                    f("explosions")
+                );
+                lines.push(format!("  {:<8} {}", d.label(), row));
+            }
+        }
+        lines
+    }
+}
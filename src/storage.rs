@@ -0,0 +1,81 @@
+/*
+DATA TYPES USED:
+
+1. dir              -> PathBuf, folder holding saved data
+2. path             -> &Path, one saved file
+3. Record           -> Vec<(String, String)>, key=value pairs read from or written to a file
+4. name             -> &str, operator handle turned into a safe file name
+5. io::Result<T>    -> result of file operations
+6. text             -> &str, file contents in key=value form
+*/
+
+use std::env;              // Locate the home directory
+use std::fs;               // Read and write files
+use std::io;               // Error type
+use std::path::{Path, PathBuf}; // File paths
+
+// Environment variable that overrides where data is kept
+pub const DATA_DIR_VAR: &str = "CYBERHEIST_HOME";
+
+// Key=value pairs in file order
+pub type Record = Vec<(String, String)>;
+
+// Folder for saved data: $CYBERHEIST_HOME, else ~/.cyberheist, else ./.cyberheist
+pub fn data_dir() -> PathBuf {
+    if let Some(dir) = env::var_os(DATA_DIR_VAR) {
+        return PathBuf::from(dir);
+    }
+    match env::var_os("HOME").or_else(|| env::var_os("USERPROFILE")) {
+        Some(home) => PathBuf::from(home).join(".cyberheist"),
+        None => PathBuf::from(".cyberheist"),
+    }
+}
+
+// Turn a handle into a file name (letters, digits, '-' and '_' only, lower case)
+pub fn file_stem(name: &str) -> String {
+    let stem: String = name
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|ch| if ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' { ch } else { '_' })
+        .collect();
+    if stem.is_empty() { "operator".to_string() } else { stem }
+}
+
+// Read "key=value" lines; a missing file is an empty record
+pub fn read_record(path: &Path) -> io::Result<Record> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    Ok(parse_record(&text))
+}
+
+// Parse "key=value" lines, skipping blanks and '#' comments
+pub fn parse_record(text: &str) -> Record {
+    text.lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .collect()
+}
+
+// Write "key=value" lines, creating the folder if needed
+pub fn write_record(path: &Path, record: &Record) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let text: String = record.iter().map(|(k, v)| format!("{}={}\n", k, v)).collect();
+    fs::write(path, text)
+}
+
+// Look up a value by key
+pub fn get<'a>(record: &'a Record, key: &str) -> Option<&'a str> {
+    record.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+}
+
+// Look up a number by key, defaulting to 0
+pub fn get_u32(record: &Record, key: &str) -> u32 {
+    get(record, key).and_then(|v| v.parse().ok()).unwrap_or(0)
+}
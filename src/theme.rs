@@ -0,0 +1,124 @@
+/*
+DATA TYPES USED:
+
+1. Theme            -> Enum: Classic, Colorblind, HighContrast, Mono, how terminal text is colored
+2. Role             -> Enum: what a piece of text stands for (node state, warning, danger)
+3. Style            -> Struct: theme plus the screen-reader option, kept by the engine
+4. s                -> &str, theme name from the command line
+5. Err              -> (), unit type for error
+6. code             -> Option<&str>, ANSI SGR parameters for a role (None = plain)
+7. text             -> &str, text to color
+*/
+
+use std::env;          // Read NO_COLOR
+use std::io::{self, IsTerminal}; // Is stdout a terminal
+use std::str::FromStr; // Parse theme names
+
+// Environment variable that turns color off (https://no-color.org)
+pub const NO_COLOR_VAR: &str = "NO_COLOR";
+
+// Enum for the color themes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Theme {
+    Classic,      // Green / yellow / red
+    Colorblind,   // Blue / orange / vermillion, safe for red-green colorblindness
+    HighContrast, // Bold bright colors on solid backgrounds
+    Mono,         // No color at all
+}
+
+// Enum for what a colored piece of text means
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Compromised, // A hacked node
+    Vulnerable,  // An easy node
+    Secure,      // A hardened node
+    Exploded,    // A destroyed node
+    Honeypot,    // An exposed trap
+    Warning,     // Something to watch
+    Danger,      // Something going wrong
+}
+
+// Struct for how the engine should present text
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub theme: Theme,        // Colors for maps and tags
+    pub screen_reader: bool, // Describe the grid in words instead of drawing it
+}
+
+// Plain text by default (network clients may not understand ANSI codes)
+impl Default for Style {
+    fn default() -> Self {
+        Style {
+            theme: Theme::Mono,
+            screen_reader: false,
+        }
+    }
+}
+
+// Convert "classic" / "colorblind" / "contrast" / "mono" into Theme
+impl FromStr for Theme {
+    type Err = (); // Unit type () used as error
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "classic" | "color" => Ok(Theme::Classic),
+            "colorblind" | "cb" => Ok(Theme::Colorblind),
+            "contrast" | "high-contrast" => Ok(Theme::HighContrast),
+            "mono" | "none" | "plain" => Ok(Theme::Mono),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Theme {
+    // Classic, unless NO_COLOR is set to anything or output goes to a pipe or file
+    pub fn from_env() -> Self {
+        match env::var_os(NO_COLOR_VAR) {
+            Some(v) if !v.is_empty() => Theme::Mono,
+            _ if !io::stdout().is_terminal() => Theme::Mono,
+            _ => Theme::Classic,
+        }
+    }
+
+    // ANSI SGR parameters for a role
+    fn code(&self, role: Role) -> Option<&'static str> {
+        match (self, role) {
+            (Theme::Mono, _) => None,
+            (_, Role::Secure) if *self != Theme::HighContrast => None,
+            (Theme::Classic, Role::Compromised) => Some("32"),
+            (Theme::Classic, Role::Vulnerable) => Some("33"),
+            (Theme::Classic, Role::Exploded | Role::Danger) => Some("31"),
+            (Theme::Classic, Role::Honeypot) => Some("35"),
+            (Theme::Classic, Role::Warning) => Some("33"),
+            (Theme::Colorblind, Role::Compromised) => Some("38;5;33"), // Blue
+            (Theme::Colorblind, Role::Vulnerable | Role::Warning) => Some("38;5;214"), // Orange
+            (Theme::Colorblind, Role::Exploded | Role::Danger) => Some("38;5;166"), // Vermillion
+            (Theme::Colorblind, Role::Honeypot) => Some("38;5;175"), // Reddish purple
+            (Theme::HighContrast, Role::Compromised) => Some("1;30;102"),
+            (Theme::HighContrast, Role::Vulnerable | Role::Warning) => Some("1;30;103"),
+            (Theme::HighContrast, Role::Exploded | Role::Danger) => Some("1;97;41"),
+            (Theme::HighContrast, Role::Honeypot) => Some("1;97;45"),
+            (Theme::HighContrast, Role::Secure) => Some("1;97"),
+            _ => None,
+        }
+    }
+
+    // Wrap text in the role's color
+    pub fn paint(&self, text: &str, role: Role) -> String {
+        match self.code(role) {
+            Some(code) => format!("\x1b[{}m{}\x1b[0m", code, text),
+            None => text.to_string(),
+        }
+    }
+}
+
+impl Style {
+    // Style for a terminal: the chosen theme, else Classic unless NO_COLOR is set or stdout is not a terminal
+    pub fn for_terminal(theme: Option<Theme>, screen_reader: bool) -> Self {
+        Style {
+            // Screen readers read escape codes aloud, so they always get plain text
+            theme: if screen_reader { Theme::Mono } else { theme.unwrap_or_else(Theme::from_env) },
+            screen_reader,
+        }
+    }
+}
@@ -0,0 +1,202 @@
+/*
+DATA TYPES USED:
+
+1. Step             -> Struct: one scripted lesson, the action it waits for and when it is done
+2. STEPS            -> [Step; 6], the lessons in order
+3. LAYOUT           -> [&str; 5], the fixed tutorial firewall, one string per row
+4. heist            -> Heist struct, a single-operator heist on the fixed board
+5. action           -> Action enum, parsed player action
+6. wants            -> fn(&Action) -> bool, whether an action is the one the step teaches
+7. done             -> fn(&Heist) -> bool, whether the step has been completed
+8. explain          -> Option<fn(&Heist) -> Vec<String>>, extra lines once the step is done
+9. map              -> bool, whether the lesson shows the firewall map
+10. record          -> GuessRecord, the latest code guess and its feedback
+11. tr!             -> macro (from i18n), message text in the chosen language
+*/
+
+use std::io::{self, Write}; // For input/output and flushing stdout
+
+use crate::actions::Action; // Enum for player actions
+use crate::engine::{GameResult, Heist, PlayMode, RuleOptions}; // Game engine
+use crate::game::play; // Free play once the lessons are over
+use crate::i18n::tr; // Translated text
+use crate::notebook::format_code; // Print a code as digits
+use crate::security::firewall::grid_from_layout; // Fixed firewall
+use crate::security::hints::ScanTarget; // Targeted scans
+use crate::security::vault::LockKind; // Vault lock kinds
+use crate::security::Difficulty; // Difficulty enum
+use crate::theme::Style; // Terminal colors
+use crate::ui::{display_header, print_lines}; // UI helper functions
+use crate::utils::read_input; // Read a line of input
+
+// Mission seed, vault code and firewall every tutorial uses
+const TUTORIAL_SEED: u64 = 1;
+const TUTORIAL_CODE: [u8; 4] = [5, 2, 8, 1];
+const LAYOUT: [&str; 5] = ["vvsvs", "svshs", "vssvs", "shsvs", "svsvs"];
+
+// Struct describing one scripted lesson (text fields are catalog keys)
+struct Step {
+    title: &'static str,                        // Shown in the step header
+    lines: &'static str,                        // What to learn and what to type
+    wants: fn(&Action) -> bool,                 // The action this step waits for
+    done: fn(&Heist) -> bool,                   // Whether the lesson is complete
+    nudge: &'static str,                        // Shown when another action is typed
+    retry: Option<&'static str>,                // Shown when the right action did not work out
+    explain: Option<fn(&Heist) -> Vec<String>>, // Extra lines once the step is done
+    map: bool,                                  // Show the firewall map with the lesson
+}
+
+// The lessons, in order
+const STEPS: [Step; 6] = [
+    Step {
+        title: "tut.scan_title",
+        lines: "tut.scan_lines",
+        wants: |a| matches!(a, Action::Scan(ScanTarget::Code)),
+        done: |_| true,
+        nudge: "tut.scan_nudge",
+        retry: None,
+        explain: None,
+        map: false,
+    },
+    Step {
+        title: "tut.hack_title",
+        lines: "tut.hack_lines",
+        wants: |a| matches!(a, Action::HackNode(..)),
+        done: |h| h.system.owned_nodes(0) > 0,
+        nudge: "tut.hack_nudge",
+        retry: Some("tut.hack_retry"),
+        explain: None,
+        map: true,
+    },
+    Step {
+        title: "tut.guess_title",
+        lines: "tut.guess_lines",
+        wants: |a| matches!(a, Action::Guess(_)),
+        done: |h| h.seats[0].code_guesses > 0,
+        nudge: "tut.guess_nudge",
+        retry: Some("tut.guess_retry"),
+        explain: Some(explain_feedback),
+        map: false,
+    },
+    Step {
+        title: "tut.notes_title",
+        lines: "tut.notes_lines",
+        wants: |a| matches!(a, Action::Analyze),
+        done: |_| true,
+        nudge: "tut.notes_nudge",
+        retry: None,
+        explain: None,
+        map: false,
+    },
+    Step {
+        title: "tut.narrow_title",
+        lines: "tut.narrow_lines",
+        wants: |a| matches!(a, Action::Guess(_)),
+        done: |h| h.seats[0].code_guesses > 1,
+        nudge: "tut.narrow_nudge",
+        retry: Some("tut.narrow_retry"),
+        explain: Some(explain_feedback),
+        map: false,
+    },
+    Step {
+        title: "tut.brute_title",
+        lines: "tut.brute_lines",
+        wants: |a| matches!(a, Action::BruteForce),
+        done: |_| true,
+        nudge: "tut.brute_nudge",
+        retry: None,
+        explain: None,
+        map: false,
+    },
+];
+
+// Interactive tutorial: scripted lessons on a fixed board, then free play
+pub fn run_tutorial(style: Style) -> GameResult {
+    display_header();
+    println!("{}", tr!("tut.handle"));
+    let name = match read_input() {
+        Ok(s) if !s.is_empty() => s,
+        _ => tr!("tut.default_handle"),
+    };
+
+    // A single operator on easy, with one code lock that never re-keys
+    let mut heist = Heist::new(&[name], Difficulty::Easy, PlayMode::Competitive, None);
+    heist.apply_rules(&RuleOptions {
+        rotate_every: Some(0),
+        rotate_alert: Some(0),
+        vault: Some(vec![LockKind::Code]),
+        seed: Some(TUTORIAL_SEED),
+        ..RuleOptions::default()
+    });
+    heist.stage(TUTORIAL_CODE, grid_from_layout(&LAYOUT));
+    heist.style = style;
+    print_lines(&heist.intro_lines());
+
+    for (i, step) in STEPS.iter().enumerate() {
+        // A lucky guess makes the remaining code lessons moot
+        if heist.seats[0].locks_open > 0 {
+            println!("\n{}", tr!("tut.skip_ahead"));
+            break;
+        }
+        println!("\n{}", tr!("tut.step", i + 1, STEPS.len(), tr!(step.title)));
+        println!("{}", tr!(step.lines));
+        if step.map {
+            print_lines(&heist.map_lines());
+        }
+
+        loop {
+            let outcome = heist.begin_turn();
+            print_lines(&outcome.lines);
+            if let Some(result) = heist.take_result() {
+                return result;
+            }
+
+            print!("tutorial> ");
+            io::stdout().flush().ok();
+            let input = match read_input() {
+                Ok(s) => s,
+                Err(_) => return GameResult::Failure(tr!("game.input_error")),
+            };
+
+            // Only the action being taught (or retreat) goes through
+            let action = match input.parse::<Action>() {
+                Ok(a) if (step.wants)(&a) || matches!(a, Action::Retreat) => a,
+                _ => {
+                    println!("{}", tr!(step.nudge));
+                    continue;
+                }
+            };
+
+            let outcome = heist.apply(heist.turn, action);
+            print_lines(&outcome.lines);
+            if let Some(result) = heist.take_result() {
+                return result;
+            }
+            if (step.done)(&heist) {
+                if let Some(explain) = step.explain {
+                    print_lines(&explain(&heist));
+                }
+                break;
+            }
+            if let Some(retry) = step.retry {
+                println!("{}", tr!(retry));
+            }
+        }
+    }
+
+    println!("\n{}", tr!("tut.complete"));
+    play(&mut heist)
+}
+
+// Walk through the feedback on the latest code guess
+fn explain_feedback(heist: &Heist) -> Vec<String> {
+    let Some(record) = heist.seats[0].notebook.guesses.last() else {
+        return Vec::new();
+    };
+    vec![
+        tr!("tut.feedback", format_code(&record.guess), record.correct, record.misplaced),
+        format!("  {}", tr!("tut.feedback_correct", record.correct)),
+        format!("  {}", tr!("tut.feedback_misplaced", record.misplaced)),
+        format!("  {}", tr!("tut.feedback_absent", 4 - record.correct - record.misplaced)),
+    ]
+}
@@ -1,17 +1,59 @@
+use crate::i18n::tr; // Translated text
+
+// Commands listed in the help text, with their catalog keys
+const HELP: [(&str, &str); 18] = [
+    ("scan (s)", "help.scan"),
+    ("scan code", "help.scan_code"),
+    ("scan grid", "help.scan_grid"),
+    ("hack (h)", "help.hack"),
+    ("hack r c", "help.hack_node"),
+    ("route r c", "help.route"),
+    ("route r c go", "help.route_go"),
+    ("route", "help.route_next"),
+    ("probe", "help.probe"),
+    ("probe r c", "help.probe_node"),
+    ("guess ...", "help.guess"),
+    ("brute (b)", "help.brute"),
+    ("wait (w)", "help.wait"),
+    ("undo [n] (u)", "help.undo"),
+    ("status", "help.status"),
+    ("analyze (a)", "help.analyze"),
+    ("retreat (r)", "help.retreat"),
+    ("help", "help.help"),
+];
+
+// One example line per vault lock, shown under "guess"
+const GUESS_FORMS: [&str; 6] = [
+    "help.guess_code",
+    "help.guess_pattern",
+    "help.guess_timing",
+    "help.guess_word",
+    "help.guess_dial",
+    "help.guess_lights",
+];
+
 pub fn display_header() {
     println!("=========================================");
-    println!("        CYBERHEIST: TERMINAL INFILTRATION");
+    println!("        {}", tr!("ui.title"));
     println!("=========================================");
 }
 
-pub fn show_help() {
-    println!();
-    println!("Available actions:");
-    println!("  scan (s)      - System scan for hints");
-    println!("  hack (h)      - Hack firewall node / guess code");
-    println!("  brute (b)     - Brute force attempt");
-    println!("  status        - Show mission status");
-    println!("  retreat (r)   - Abort mission");
-    println!("  help          - This help text");
-    println!();
+// Print lines produced by the engine
+pub fn print_lines(lines: &[String]) {
+    for line in lines {
+        println!("{}", line);
+    }
+}
+
+// Help text shared by the terminal game and the server
+pub fn help_lines() -> Vec<String> {
+    let mut lines = vec![String::new(), tr!("help.title")];
+    for (cmd, key) in HELP {
+        lines.push(format!("  {:<13} - {}", cmd, tr!(key)));
+        if key == "help.guess" {
+            lines.extend(GUESS_FORMS.iter().map(|k| format!("{:20}{}", "", tr!(k))));
+        }
+    }
+    lines.push(String::new());
+    lines
 }
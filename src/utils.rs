@@ -1,5 +1,7 @@
 use std::io; 
 
+use crate::storage::{self, Record}; // Saved key=value pairs
+
 // Generic clamp function to constrain a value between low and high
 pub fn clamp_generic<T: Ord>(val: T, low: T, high: T) -> T {
     if val < low {
@@ -11,6 +13,20 @@ pub fn clamp_generic<T: Ord>(val: T, low: T, high: T) -> T {
     }
 }
 
+// Overwrite a counter in a saved record, adding it if missing
+pub fn set_count(record: &mut Record, key: &str, value: u32) {
+    match record.iter_mut().find(|(k, _)| k == key) {
+        Some((_, v)) => *v = value.to_string(),
+        None => record.push((key.to_string(), value.to_string())),
+    }
+}
+
+// Add to a counter in a saved record (a missing counter starts at 0)
+pub fn add_count(record: &mut Record, key: &str, amount: u32) {
+    let value = storage::get_u32(record, key) + amount;
+    set_count(record, key, value);
+}
+
 // Function to read a line of input from the user
 pub fn read_input() -> Result<String, io::Error> {
     let mut buf = String::new();         // Buffer to store input